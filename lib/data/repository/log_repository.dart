@@ -1,21 +1,40 @@
 import 'package:lkl2/src/rust/file.dart' as rust_file;
 
 abstract class ILogRepository {
-  Future<void> openFile(String path);
+  /// [mapFile] memory-maps the file instead of copying its lines into the
+  /// database. The file must not be truncated while it is open.
+  Future<void> openFile(String path, {bool mapFile = false});
   Future<rust_file.FileStatus> getFileStatus();
   Future<rust_file.Logs> getLogs({
     required String filterSql,
     required String ftsQuery,
+    rust_file.SearchOptions search = const rust_file.SearchOptions(
+      mode: rust_file.SearchMode.phrase,
+      caseSensitive: false,
+      wholeWord: false,
+    ),
+    List<rust_file.SortKey> sort = const [],
     required int limit,
     required int offset,
   });
   Future<String?> getLogDetail(int id);
+  Future<List<rust_file.FacetValue>> getFacets({
+    required String field,
+    required String filterSql,
+    required String ftsQuery,
+    rust_file.SearchOptions search = const rust_file.SearchOptions(
+      mode: rust_file.SearchMode.phrase,
+      caseSensitive: false,
+      wholeWord: false,
+    ),
+    required int limit,
+  });
 }
 
 class LogRepository implements ILogRepository {
   @override
-  Future<void> openFile(String path) {
-    return rust_file.openFile(path: path);
+  Future<void> openFile(String path, {bool mapFile = false}) {
+    return rust_file.openFile(path: path, mapFile: mapFile);
   }
 
   @override
@@ -27,12 +46,20 @@ class LogRepository implements ILogRepository {
   Future<rust_file.Logs> getLogs({
     required String filterSql,
     required String ftsQuery,
+    rust_file.SearchOptions search = const rust_file.SearchOptions(
+      mode: rust_file.SearchMode.phrase,
+      caseSensitive: false,
+      wholeWord: false,
+    ),
+    List<rust_file.SortKey> sort = const [],
     required int limit,
     required int offset,
   }) {
     return rust_file.getLogs(
       filterSql: filterSql,
       ftsQuery: ftsQuery,
+      search: search,
+      sort: sort,
       limit: limit,
       offset: offset,
     );
@@ -42,4 +69,25 @@ class LogRepository implements ILogRepository {
   Future<String?> getLogDetail(int id) {
     return rust_file.getLogDetail(id: id);
   }
+
+  @override
+  Future<List<rust_file.FacetValue>> getFacets({
+    required String field,
+    required String filterSql,
+    required String ftsQuery,
+    rust_file.SearchOptions search = const rust_file.SearchOptions(
+      mode: rust_file.SearchMode.phrase,
+      caseSensitive: false,
+      wholeWord: false,
+    ),
+    required int limit,
+  }) {
+    return rust_file.getFacets(
+      field: field,
+      filterSql: filterSql,
+      ftsQuery: ftsQuery,
+      search: search,
+      limit: limit,
+    );
+  }
 }
@@ -231,4 +231,20 @@ class LogProvider extends ChangeNotifier {
   Future<String?> getDetail(int id) async {
     return await _repository.getLogDetail(id);
   }
+
+  /// Most frequent values of [field] among the logs matching the applied
+  /// filters and search, for suggesting filter values.
+  Future<List<FacetValue>> getFacets(String field, {int limit = 50}) async {
+    try {
+      return await _repository.getFacets(
+        field: field,
+        filterSql: _filterSql,
+        ftsQuery: _lastSearchQuery,
+        limit: limit,
+      );
+    } catch (e) {
+      debugPrint("Error loading facets: $e");
+      return [];
+    }
+  }
 }
@@ -8,12 +8,15 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'file.freezed.dart';
 
+// These functions are ignored because they are not marked as `pub`: `export_reader`, `to_log`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AppState`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
 /// 1.1 dart打开文件 -> rust后台开启线程处理文件
-Future<void> openFile({required String path}) =>
-    RustLib.instance.api.crateFileOpenFile(path: path);
+/// map_file: 为 true 时以内存映射读取文件，原始行不入库只记录偏移 (省内存)；
+/// 打开期间文件被截断会导致进程崩溃 (SIGBUS)，只用于不再写入的文件
+Future<void> openFile({required String path, required bool mapFile}) =>
+    RustLib.instance.api.crateFileOpenFile(path: path, mapFile: mapFile);
 
 /// 1.2 dart查询文件状态
 Future<FileStatus> getFileStatus() =>
@@ -22,22 +25,171 @@ Future<FileStatus> getFileStatus() =>
 /// 1.3 dart查询日志 （这里不返回详细信息)
 /// filter_sql: SQL WHERE clause fragment (e.g., "eventName = 'Error'")
 /// fts_query: Full text search query
+/// search: fts_query 的解释方式及大小写、全词匹配选项
+/// sort: 排序字段，按优先级排列；为空则按加载顺序
 Future<Logs> getLogs({
   required String filterSql,
   required String ftsQuery,
+  required SearchOptions search,
+  required List<SortKey> sort,
   required int limit,
   required int offset,
 }) => RustLib.instance.api.crateFileGetLogs(
   filterSql: filterSql,
   ftsQuery: ftsQuery,
+  search: search,
+  sort: sort,
   limit: limit,
   offset: offset,
 );
 
 /// 1.4 dart查询特定日志的详细信息
+/// 非 UTF-8 字节显示为 �，需要原始字节时用 get_log_raw
 Future<String?> getLogDetail({required int id}) =>
     RustLib.instance.api.crateFileGetLogDetail(id: id);
 
+/// 1.4.1 dart查询日志在文件中的原始字节 (不含换行符)
+Future<Uint8List?> getLogRaw({required int id}) =>
+    RustLib.instance.api.crateFileGetLogRaw(id: id);
+
+/// 1.5 dart查询某条日志前后的上下文 (类似 grep -C)
+/// same_field: 只看与该日志此字段值相同的日志 (例如 "sourceNodeId")，为空则不限制
+Future<List<Log>> getContext({
+  required int id,
+  required int before,
+  required int after,
+  String? sameField,
+}) => RustLib.instance.api.crateFileGetContext(
+  id: id,
+  before: before,
+  after: after,
+  sameField: sameField,
+);
+
+/// 1.6 dart查询某个字段在当前过滤条件下的取值分布 (按出现次数降序)
+/// filter_sql/fts_query/search: 同 get_logs
+/// limit: 最多返回多少个取值
+Future<List<FacetValue>> getFacets({
+  required String field,
+  required String filterSql,
+  required String ftsQuery,
+  required SearchOptions search,
+  required int limit,
+}) => RustLib.instance.api.crateFileGetFacets(
+  field: field,
+  filterSql: filterSql,
+  ftsQuery: ftsQuery,
+  search: search,
+  limit: limit,
+);
+
+/// 1.7 dart查询当前过滤条件下日志的时间分布 (按 ts 列分桶)
+/// buckets: 期望的桶数量，实际桶宽会取整到 1s/1m/1h 等
+/// 选中时间范围后可用 filter_sql "ts >= a AND ts < b" 过滤
+Future<Histogram> getHistogram({
+  required String filterSql,
+  required String ftsQuery,
+  required SearchOptions search,
+  required int buckets,
+}) => RustLib.instance.api.crateFileGetHistogram(
+  filterSql: filterSql,
+  ftsQuery: ftsQuery,
+  search: search,
+  buckets: buckets,
+);
+
+/// 1.8 dart查询规则文件中的预设视图，选中后用其参数调用 get_logs
+Future<List<View>> listViews() => RustLib.instance.api.crateFileListViews();
+
+/// 1.9 dart导出当前过滤条件下的全部日志 (忽略分页, 按 sort 排序)，返回导出的行数
+/// 导出期间可调用 get_export_progress 查询进度
+Future<int> exportLogs({
+  required String path,
+  required ExportFormat format,
+  required String filterSql,
+  required String ftsQuery,
+  required SearchOptions search,
+  required List<SortKey> sort,
+}) => RustLib.instance.api.crateFileExportLogs(
+  path: path,
+  format: format,
+  filterSql: filterSql,
+  ftsQuery: ftsQuery,
+  search: search,
+  sort: sort,
+);
+
+/// 1.10 dart查询导出进度 (已写入的行数)
+Future<int> getExportProgress() =>
+    RustLib.instance.api.crateFileGetExportProgress();
+
+/// 1.11 dart将当前过滤条件下日志的原始行按原顺序导出为日志文件 (字节不变)，返回导出的行数
+/// gzip: 是否压缩为 .gz；导出期间可调用 get_export_progress 查询进度
+Future<int> exportRawLines({
+  required String path,
+  required bool gzip,
+  required String filterSql,
+  required String ftsQuery,
+  required SearchOptions search,
+}) => RustLib.instance.api.crateFileExportRawLines(
+  path: path,
+  gzip: gzip,
+  filterSql: filterSql,
+  ftsQuery: ftsQuery,
+  search: search,
+);
+
+/// 1.12 dart查询规则文件中的日志列表布局 ([[col]]) 及可过滤的字段
+Future<Layout> getLayout() => RustLib.instance.api.crateFileGetLayout();
+
+/// 1.13 dart根据文件前 sample_lines 行推断字段，生成建议的规则文件 (不需要先打开文件)
+Future<InferredRules> inferRules({
+  required String path,
+  required int sampleLines,
+}) => RustLib.instance.api.crateFileInferRules(
+  path: path,
+  sampleLines: sampleLines,
+);
+
+enum CellStyle {
+  text,
+  /// 灰色小字
+  meta,
+  /// 灰色底的小标签
+  tag,
+  /// 主题色底的小标签
+  colorTag,
+}
+
+enum ExportFormat {
+  csv,
+  /// 每行一个 JSON 对象 (id 及各字段)
+  jsonl,
+  /// 原始日志行
+  jsonlRaw,
+  parquet,
+  /// 按日志列表布局渲染的显示文本 (CSV，每个单元格一列)
+  cells,
+}
+
+class FacetValue {
+  final String value;
+  final int count;
+
+  const FacetValue({required this.value, required this.count});
+
+  @override
+  int get hashCode => value.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FacetValue &&
+          runtimeType == other.runtimeType &&
+          value == other.value &&
+          count == other.count;
+}
+
 @freezed
 sealed class FileStatus with _$FileStatus {
   const FileStatus._();
@@ -48,14 +200,196 @@ sealed class FileStatus with _$FileStatus {
   const factory FileStatus.error(String field0) = FileStatus_Error;
 }
 
+/// counts[i] 为 [start_ms + i * bucket_ms, start_ms + (i + 1) * bucket_ms) 内的日志数
+class Histogram {
+  final PlatformInt64 startMs;
+  final PlatformInt64 bucketMs;
+  final Uint32List counts;
+
+  const Histogram({
+    required this.startMs,
+    required this.bucketMs,
+    required this.counts,
+  });
+
+  @override
+  int get hashCode => startMs.hashCode ^ bucketMs.hashCode ^ counts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Histogram &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          bucketMs == other.bucketMs &&
+          counts == other.counts;
+}
+
+/// name: 建议的列名；path: [logs] 中的路径，如 "$line.Event.eventName"
+/// fill_rate: 有非 null 值的 JSON 行占比 (0~1)；repeated: 取值只有少数几种且重复出现
+class InferredField {
+  final String name;
+  final String path;
+  final ValueKind kind;
+  final double fillRate;
+  final bool repeated;
+
+  const InferredField({
+    required this.name,
+    required this.path,
+    required this.kind,
+    required this.fillRate,
+    required this.repeated,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      path.hashCode ^
+      kind.hashCode ^
+      fillRate.hashCode ^
+      repeated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InferredField &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          path == other.path &&
+          kind == other.kind &&
+          fillRate == other.fillRate &&
+          repeated == other.repeated;
+}
+
+/// 根据日志文件前若干行推断出的规则
+/// rules: 建议的规则文件内容 (lkl2.toml 格式)；time_field: 建议的 [time] 字段
+/// json_lines: 采样的非空行中为 JSON 对象的行数
+class InferredRules {
+  final String rules;
+  final List<InferredField> fields;
+  final String? timeField;
+  final int sampledLines;
+  final int jsonLines;
+
+  const InferredRules({
+    required this.rules,
+    required this.fields,
+    this.timeField,
+    required this.sampledLines,
+    required this.jsonLines,
+  });
+
+  @override
+  int get hashCode =>
+      rules.hashCode ^
+      fields.hashCode ^
+      timeField.hashCode ^
+      sampledLines.hashCode ^
+      jsonLines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InferredRules &&
+          runtimeType == other.runtimeType &&
+          rules == other.rules &&
+          fields == other.fields &&
+          timeField == other.timeField &&
+          sampledLines == other.sampledLines &&
+          jsonLines == other.jsonLines;
+}
+
+/// 规则文件中 [[col]] 定义的日志列表布局
+/// fields: [logs] 中定义的字段，按名称排序
+class Layout {
+  final List<LayoutColumn> columns;
+  final List<String> fields;
+
+  const Layout({required this.columns, required this.fields});
+
+  @override
+  int get hashCode => columns.hashCode ^ fields.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Layout &&
+          runtimeType == other.runtimeType &&
+          columns == other.columns &&
+          fields == other.fields;
+}
+
+/// expr: 显示表达式，如 "$eventName"、"time($eventTime)"
+/// ellipsis: 超出 max_lines 时是否显示省略号
+class LayoutCell {
+  final String expr;
+  final CellStyle style;
+  final int maxLines;
+  final bool ellipsis;
+
+  const LayoutCell({
+    required this.expr,
+    required this.style,
+    required this.maxLines,
+    required this.ellipsis,
+  });
+
+  @override
+  int get hashCode =>
+      expr.hashCode ^ style.hashCode ^ maxLines.hashCode ^ ellipsis.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LayoutCell &&
+          runtimeType == other.runtimeType &&
+          expr == other.expr &&
+          style == other.style &&
+          maxLines == other.maxLines &&
+          ellipsis == other.ellipsis;
+}
+
+/// 日志列表中的一列，rows 自上而下排列
+/// width: 固定宽度 (逻辑像素)；flex: 按比例分配剩余宽度；都为空时 flex 为 1
+class LayoutColumn {
+  final double? width;
+  final int? flex;
+  final List<LayoutCell> rows;
+
+  const LayoutColumn({this.width, this.flex, required this.rows});
+
+  @override
+  int get hashCode => width.hashCode ^ flex.hashCode ^ rows.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LayoutColumn &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          flex == other.flex &&
+          rows == other.rows;
+}
+
 class Log {
   final int id;
   final Map<String, String> fields;
+  /// fts_query 的命中位置，用于高亮；没有全文搜索时为空
+  final List<MatchSpan> matches;
+  /// 按 get_layout 中各列、各单元格的顺序渲染好的显示文本
+  final List<String> cells;
 
-  const Log({required this.id, required this.fields});
+  const Log({
+    required this.id,
+    required this.fields,
+    required this.matches,
+    required this.cells,
+  });
 
   @override
-  int get hashCode => id.hashCode ^ fields.hashCode;
+  int get hashCode =>
+      id.hashCode ^ fields.hashCode ^ matches.hashCode ^ cells.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -63,17 +397,21 @@ class Log {
       other is Log &&
           runtimeType == other.runtimeType &&
           id == other.id &&
-          fields == other.fields;
+          fields == other.fields &&
+          matches == other.matches &&
+          cells == other.cells;
 }
 
 class Logs {
   final List<Log> logs;
   final int totalCount;
+  /// 全文搜索的执行方式；没有全文搜索时为空
+  final SearchStrategy? strategy;
 
-  const Logs({required this.logs, required this.totalCount});
+  const Logs({required this.logs, required this.totalCount, this.strategy});
 
   @override
-  int get hashCode => logs.hashCode ^ totalCount.hashCode;
+  int get hashCode => logs.hashCode ^ totalCount.hashCode ^ strategy.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -81,5 +419,157 @@ class Logs {
       other is Logs &&
           runtimeType == other.runtimeType &&
           logs == other.logs &&
-          totalCount == other.totalCount;
+          totalCount == other.totalCount &&
+          strategy == other.strategy;
+}
+
+/// field 为字段名或 "raw"，[start, end) 为字符偏移
+class MatchSpan {
+  final String field;
+  final int start;
+  final int end;
+
+  const MatchSpan({
+    required this.field,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MatchSpan &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          start == other.start &&
+          end == other.end;
+}
+
+/// fts_query 的解释方式
+enum SearchMode {
+  /// 整体作为子串
+  phrase,
+  /// 多个词用 AND / OR / NOT / 括号组合，"..." 为短语，相邻的词默认 AND
+  boolean,
+  /// 正则表达式 (Rust regex 语法)
+  regex,
+  /// 等同于 Phrase + case_sensitive
+  literal,
+}
+
+class SearchOptions {
+  final SearchMode mode;
+  /// 区分大小写，否则忽略大小写
+  final bool caseSensitive;
+  /// 全词匹配：命中前后不能紧挨字母、数字或下划线
+  final bool wholeWord;
+
+  const SearchOptions({
+    required this.mode,
+    required this.caseSensitive,
+    required this.wholeWord,
+  });
+
+  @override
+  int get hashCode =>
+      mode.hashCode ^ caseSensitive.hashCode ^ wholeWord.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchOptions &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          caseSensitive == other.caseSensitive &&
+          wholeWord == other.wholeWord;
+}
+
+enum SearchStrategy {
+  /// 走 trigram 索引
+  trigram,
+  /// 有少于 3 个字符的词或为正则，逐行扫描
+  scan,
+}
+
+class SortKey {
+  final String column;
+  final bool descending;
+  final SortType sortType;
+
+  const SortKey({
+    required this.column,
+    required this.descending,
+    required this.sortType,
+  });
+
+  @override
+  int get hashCode => column.hashCode ^ descending.hashCode ^ sortType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SortKey &&
+          runtimeType == other.runtimeType &&
+          column == other.column &&
+          descending == other.descending &&
+          sortType == other.sortType;
+}
+
+enum SortType { text, integer, real }
+
+/// 采样中该路径上值的 JSON 类型；null 不计入，整数与小数并存时为 Float
+enum ValueKind {
+  /// 只出现过 null
+  null_,
+  bool,
+  integer,
+  float,
+  string,
+  array,
+  /// 多种类型并存
+  mixed,
+}
+
+/// 规则文件中 [[view]] 定义的预设视图
+/// columns: 显示的列，为空则全部显示
+class View {
+  final String name;
+  final String filterSql;
+  final String ftsQuery;
+  final SearchOptions search;
+  final List<SortKey> sort;
+  final List<String> columns;
+
+  const View({
+    required this.name,
+    required this.filterSql,
+    required this.ftsQuery,
+    required this.search,
+    required this.sort,
+    required this.columns,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      filterSql.hashCode ^
+      ftsQuery.hashCode ^
+      search.hashCode ^
+      sort.hashCode ^
+      columns.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is View &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          filterSql == other.filterSql &&
+          ftsQuery == other.ftsQuery &&
+          search == other.search &&
+          sort == other.sort &&
+          columns == other.columns;
 }
@@ -64,7 +64,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 23399159;
+  int get rustContentHash => 1695968060;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -75,18 +75,72 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<int> crateFileExportLogs({
+    required String path,
+    required ExportFormat format,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required List<SortKey> sort,
+  });
+
+  Future<int> crateFileExportRawLines({
+    required String path,
+    required bool gzip,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+  });
+
+  Future<List<Log>> crateFileGetContext({
+    required int id,
+    required int before,
+    required int after,
+    String? sameField,
+  });
+
+  Future<int> crateFileGetExportProgress();
+
+  Future<List<FacetValue>> crateFileGetFacets({
+    required String field,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required int limit,
+  });
+
   Future<FileStatus> crateFileGetFileStatus();
 
+  Future<Histogram> crateFileGetHistogram({
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required int buckets,
+  });
+
+  Future<Layout> crateFileGetLayout();
+
   Future<String?> crateFileGetLogDetail({required int id});
 
+  Future<Uint8List?> crateFileGetLogRaw({required int id});
+
   Future<Logs> crateFileGetLogs({
     required String filterSql,
     required String ftsQuery,
+    required SearchOptions search,
+    required List<SortKey> sort,
     required int limit,
     required int offset,
   });
 
-  Future<void> crateFileOpenFile({required String path});
+  Future<InferredRules> crateFileInferRules({
+    required String path,
+    required int sampleLines,
+  });
+
+  Future<List<View>> crateFileListViews();
+
+  Future<void> crateFileOpenFile({required String path, required bool mapFile});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -98,11 +152,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<FileStatus> crateFileGetFileStatus() {
+  Future<int> crateFileExportLogs({
+    required String path,
+    required ExportFormat format,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required List<SortKey> sort,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_export_format(format, serializer);
+          sse_encode_String(filterSql, serializer);
+          sse_encode_String(ftsQuery, serializer);
+          sse_encode_box_autoadd_search_options(search, serializer);
+          sse_encode_list_sort_key(sort, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -110,6 +177,180 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             port: port_,
           );
         },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileExportLogsConstMeta,
+        argValues: [path, format, filterSql, ftsQuery, search, sort],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileExportLogsConstMeta => const TaskConstMeta(
+    debugName: "export_logs",
+    argNames: ["path", "format", "filterSql", "ftsQuery", "search", "sort"],
+  );
+
+  @override
+  Future<int> crateFileExportRawLines({
+    required String path,
+    required bool gzip,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(gzip, serializer);
+          sse_encode_String(filterSql, serializer);
+          sse_encode_String(ftsQuery, serializer);
+          sse_encode_box_autoadd_search_options(search, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileExportRawLinesConstMeta,
+        argValues: [path, gzip, filterSql, ftsQuery, search],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileExportRawLinesConstMeta => const TaskConstMeta(
+    debugName: "export_raw_lines",
+    argNames: ["path", "gzip", "filterSql", "ftsQuery", "search"],
+  );
+
+  @override
+  Future<List<Log>> crateFileGetContext({
+    required int id,
+    required int before,
+    required int after,
+    String? sameField,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          sse_encode_u_32(before, serializer);
+          sse_encode_u_32(after, serializer);
+          sse_encode_opt_String(sameField, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_log,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileGetContextConstMeta,
+        argValues: [id, before, after, sameField],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetContextConstMeta => const TaskConstMeta(
+    debugName: "get_context",
+    argNames: ["id", "before", "after", "sameField"],
+  );
+
+  @override
+  Future<int> crateFileGetExportProgress() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateFileGetExportProgressConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetExportProgressConstMeta =>
+      const TaskConstMeta(debugName: "get_export_progress", argNames: []);
+
+  @override
+  Future<List<FacetValue>> crateFileGetFacets({
+    required String field,
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(field, serializer);
+          sse_encode_String(filterSql, serializer);
+          sse_encode_String(ftsQuery, serializer);
+          sse_encode_box_autoadd_search_options(search, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_facet_value,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileGetFacetsConstMeta,
+        argValues: [field, filterSql, ftsQuery, search, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetFacetsConstMeta => const TaskConstMeta(
+    debugName: "get_facets",
+    argNames: ["field", "filterSql", "ftsQuery", "search", "limit"],
+  );
+
+  @override
+  Future<FileStatus> crateFileGetFileStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
         codec: SseCodec(
           decodeSuccessData: sse_decode_file_status,
           decodeErrorData: null,
@@ -124,6 +365,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateFileGetFileStatusConstMeta =>
       const TaskConstMeta(debugName: "get_file_status", argNames: []);
 
+  @override
+  Future<Histogram> crateFileGetHistogram({
+    required String filterSql,
+    required String ftsQuery,
+    required SearchOptions search,
+    required int buckets,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filterSql, serializer);
+          sse_encode_String(ftsQuery, serializer);
+          sse_encode_box_autoadd_search_options(search, serializer);
+          sse_encode_u_32(buckets, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_histogram,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileGetHistogramConstMeta,
+        argValues: [filterSql, ftsQuery, search, buckets],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetHistogramConstMeta => const TaskConstMeta(
+    debugName: "get_histogram",
+    argNames: ["filterSql", "ftsQuery", "search", "buckets"],
+  );
+
+  @override
+  Future<Layout> crateFileGetLayout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_layout,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileGetLayoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetLayoutConstMeta =>
+      const TaskConstMeta(debugName: "get_layout", argNames: []);
+
   @override
   Future<String?> crateFileGetLogDetail({required int id}) {
     return handler.executeNormal(
@@ -134,7 +440,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 2,
+            funcId: 9,
             port: port_,
           );
         },
@@ -152,10 +458,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateFileGetLogDetailConstMeta =>
       const TaskConstMeta(debugName: "get_log_detail", argNames: ["id"]);
 
+  @override
+  Future<Uint8List?> crateFileGetLogRaw({required int id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 10,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileGetLogRawConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileGetLogRawConstMeta =>
+      const TaskConstMeta(debugName: "get_log_raw", argNames: ["id"]);
+
   @override
   Future<Logs> crateFileGetLogs({
     required String filterSql,
     required String ftsQuery,
+    required SearchOptions search,
+    required List<SortKey> sort,
     required int limit,
     required int offset,
   }) {
@@ -165,12 +501,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(filterSql, serializer);
           sse_encode_String(ftsQuery, serializer);
+          sse_encode_box_autoadd_search_options(search, serializer);
+          sse_encode_list_sort_key(sort, serializer);
           sse_encode_u_32(limit, serializer);
           sse_encode_u_32(offset, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 3,
+            funcId: 11,
             port: port_,
           );
         },
@@ -179,7 +517,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta: kCrateFileGetLogsConstMeta,
-        argValues: [filterSql, ftsQuery, limit, offset],
+        argValues: [filterSql, ftsQuery, search, sort, limit, offset],
         apiImpl: this,
       ),
     );
@@ -187,41 +525,108 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateFileGetLogsConstMeta => const TaskConstMeta(
     debugName: "get_logs",
-    argNames: ["filterSql", "ftsQuery", "limit", "offset"],
+    argNames: ["filterSql", "ftsQuery", "search", "sort", "limit", "offset"],
   );
 
   @override
-  Future<void> crateFileOpenFile({required String path}) {
+  Future<InferredRules> crateFileInferRules({
+    required String path,
+    required int sampleLines,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_u_32(sampleLines, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 4,
+            funcId: 12,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_inferred_rules,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateFileOpenFileConstMeta,
-        argValues: [path],
+        constMeta: kCrateFileInferRulesConstMeta,
+        argValues: [path, sampleLines],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateFileOpenFileConstMeta =>
-      const TaskConstMeta(debugName: "open_file", argNames: ["path"]);
+  TaskConstMeta get kCrateFileInferRulesConstMeta => const TaskConstMeta(
+    debugName: "infer_rules",
+    argNames: ["path", "sampleLines"],
+  );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<List<View>> crateFileListViews() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_view,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFileListViewsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileListViewsConstMeta =>
+      const TaskConstMeta(debugName: "list_views", argNames: []);
+
+  @override
+  Future<void> crateFileOpenFile({
+    required String path,
+    required bool mapFile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(mapFile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateFileOpenFileConstMeta,
+        argValues: [path, mapFile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFileOpenFileConstMeta => const TaskConstMeta(
+    debugName: "open_file",
+    argNames: ["path", "mapFile"],
+  );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
   }
 
   @protected
@@ -240,6 +645,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as String;
   }
 
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  SearchOptions dco_decode_box_autoadd_search_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_options(raw);
+  }
+
+  @protected
+  SearchStrategy dco_decode_box_autoadd_search_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_strategy(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  CellStyle dco_decode_cell_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CellStyle.values[raw as int];
+  }
+
+  @protected
+  ExportFormat dco_decode_export_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ExportFormat.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FacetValue dco_decode_facet_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FacetValue(
+      value: dco_decode_String(arr[0]),
+      count: dco_decode_u_32(arr[1]),
+    );
+  }
+
   @protected
   FileStatus dco_decode_file_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -257,12 +722,148 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Histogram dco_decode_histogram(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Histogram(
+      startMs: dco_decode_i_64(arr[0]),
+      bucketMs: dco_decode_i_64(arr[1]),
+      counts: dco_decode_list_prim_u_32_strict(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InferredField dco_decode_inferred_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InferredField(
+      name: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      kind: dco_decode_value_kind(arr[2]),
+      fillRate: dco_decode_f_64(arr[3]),
+      repeated: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  InferredRules dco_decode_inferred_rules(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return InferredRules(
+      rules: dco_decode_String(arr[0]),
+      fields: dco_decode_list_inferred_field(arr[1]),
+      timeField: dco_decode_opt_String(arr[2]),
+      sampledLines: dco_decode_u_32(arr[3]),
+      jsonLines: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  Layout dco_decode_layout(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Layout(
+      columns: dco_decode_list_layout_column(arr[0]),
+      fields: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  LayoutCell dco_decode_layout_cell(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LayoutCell(
+      expr: dco_decode_String(arr[0]),
+      style: dco_decode_cell_style(arr[1]),
+      maxLines: dco_decode_u_32(arr[2]),
+      ellipsis: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  LayoutColumn dco_decode_layout_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LayoutColumn(
+      width: dco_decode_opt_box_autoadd_f_64(arr[0]),
+      flex: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      rows: dco_decode_list_layout_cell(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<FacetValue> dco_decode_list_facet_value(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_facet_value).toList();
+  }
+
+  @protected
+  List<InferredField> dco_decode_list_inferred_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_inferred_field).toList();
+  }
+
+  @protected
+  List<LayoutCell> dco_decode_list_layout_cell(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_layout_cell).toList();
+  }
+
+  @protected
+  List<LayoutColumn> dco_decode_list_layout_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_layout_column).toList();
+  }
+
   @protected
   List<Log> dco_decode_list_log(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_log).toList();
   }
 
+  @protected
+  List<MatchSpan> dco_decode_list_match_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_match_span).toList();
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -275,15 +876,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
   }
 
+  @protected
+  List<SortKey> dco_decode_list_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sort_key).toList();
+  }
+
+  @protected
+  List<View> dco_decode_list_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_view).toList();
+  }
+
   @protected
   Log dco_decode_log(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return Log(
       id: dco_decode_u_32(arr[0]),
       fields: dco_decode_Map_String_String_None(arr[1]),
+      matches: dco_decode_list_match_span(arr[2]),
+      cells: dco_decode_list_String(arr[3]),
     );
   }
 
@@ -291,11 +906,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Logs dco_decode_logs(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return Logs(
       logs: dco_decode_list_log(arr[0]),
       totalCount: dco_decode_u_32(arr[1]),
+      strategy: dco_decode_opt_box_autoadd_search_strategy(arr[2]),
+    );
+  }
+
+  @protected
+  MatchSpan dco_decode_match_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MatchSpan(
+      field: dco_decode_String(arr[0]),
+      start: dco_decode_u_32(arr[1]),
+      end: dco_decode_u_32(arr[2]),
     );
   }
 
@@ -305,6 +934,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_String(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  SearchStrategy? dco_decode_opt_box_autoadd_search_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_search_strategy(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -316,205 +969,841 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  SearchMode dco_decode_search_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchMode.values[raw as int];
+  }
+
+  @protected
+  SearchOptions dco_decode_search_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SearchOptions(
+      mode: dco_decode_search_mode(arr[0]),
+      caseSensitive: dco_decode_bool(arr[1]),
+      wholeWord: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  SearchStrategy dco_decode_search_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SearchStrategy.values[raw as int];
+  }
+
+  @protected
+  SortKey dco_decode_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SortKey(
+      column: dco_decode_String(arr[0]),
+      descending: dco_decode_bool(arr[1]),
+      sortType: dco_decode_sort_type(arr[2]),
+    );
+  }
+
+  @protected
+  SortType dco_decode_sort_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SortType.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  ValueKind dco_decode_value_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueKind.values[raw as int];
+  }
+
+  @protected
+  View dco_decode_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return View(
+      name: dco_decode_String(arr[0]),
+      filterSql: dco_decode_String(arr[1]),
+      ftsQuery: dco_decode_String(arr[2]),
+      search: dco_decode_search_options(arr[3]),
+      sort: dco_decode_list_sort_key(arr[4]),
+      columns: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  SearchOptions sse_decode_box_autoadd_search_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_options(deserializer));
+  }
+
+  @protected
+  SearchStrategy sse_decode_box_autoadd_search_strategy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_strategy(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  CellStyle sse_decode_cell_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CellStyle.values[inner];
+  }
+
+  @protected
+  ExportFormat sse_decode_export_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ExportFormat.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FacetValue sse_decode_facet_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_value = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    return FacetValue(value: var_value, count: var_count);
+  }
+
+  @protected
+  FileStatus sse_decode_file_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return FileStatus_Uninit();
+      case 1:
+        return FileStatus_Pending();
+      case 2:
+        return FileStatus_Complete();
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return FileStatus_Error(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  Histogram sse_decode_histogram(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_i_64(deserializer);
+    var var_bucketMs = sse_decode_i_64(deserializer);
+    var var_counts = sse_decode_list_prim_u_32_strict(deserializer);
+    return Histogram(
+      startMs: var_startMs,
+      bucketMs: var_bucketMs,
+      counts: var_counts,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InferredField sse_decode_inferred_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_kind = sse_decode_value_kind(deserializer);
+    var var_fillRate = sse_decode_f_64(deserializer);
+    var var_repeated = sse_decode_bool(deserializer);
+    return InferredField(
+      name: var_name,
+      path: var_path,
+      kind: var_kind,
+      fillRate: var_fillRate,
+      repeated: var_repeated,
+    );
+  }
+
+  @protected
+  InferredRules sse_decode_inferred_rules(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rules = sse_decode_String(deserializer);
+    var var_fields = sse_decode_list_inferred_field(deserializer);
+    var var_timeField = sse_decode_opt_String(deserializer);
+    var var_sampledLines = sse_decode_u_32(deserializer);
+    var var_jsonLines = sse_decode_u_32(deserializer);
+    return InferredRules(
+      rules: var_rules,
+      fields: var_fields,
+      timeField: var_timeField,
+      sampledLines: var_sampledLines,
+      jsonLines: var_jsonLines,
+    );
+  }
+
+  @protected
+  Layout sse_decode_layout(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_columns = sse_decode_list_layout_column(deserializer);
+    var var_fields = sse_decode_list_String(deserializer);
+    return Layout(columns: var_columns, fields: var_fields);
+  }
+
+  @protected
+  LayoutCell sse_decode_layout_cell(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_expr = sse_decode_String(deserializer);
+    var var_style = sse_decode_cell_style(deserializer);
+    var var_maxLines = sse_decode_u_32(deserializer);
+    var var_ellipsis = sse_decode_bool(deserializer);
+    return LayoutCell(
+      expr: var_expr,
+      style: var_style,
+      maxLines: var_maxLines,
+      ellipsis: var_ellipsis,
+    );
+  }
+
+  @protected
+  LayoutColumn sse_decode_layout_column(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_flex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_rows = sse_decode_list_layout_cell(deserializer);
+    return LayoutColumn(width: var_width, flex: var_flex, rows: var_rows);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FacetValue> sse_decode_list_facet_value(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FacetValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_facet_value(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InferredField> sse_decode_list_inferred_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InferredField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_inferred_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LayoutCell> sse_decode_list_layout_cell(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LayoutCell>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_layout_cell(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LayoutColumn> sse_decode_list_layout_column(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LayoutColumn>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_layout_column(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Log> sse_decode_list_log(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Log>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MatchSpan> sse_decode_list_match_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MatchSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_match_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SortKey>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sort_key(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<View> sse_decode_list_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <View>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_view(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Log sse_decode_log(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_fields = sse_decode_Map_String_String_None(deserializer);
+    var var_matches = sse_decode_list_match_span(deserializer);
+    var var_cells = sse_decode_list_String(deserializer);
+    return Log(
+      id: var_id,
+      fields: var_fields,
+      matches: var_matches,
+      cells: var_cells,
+    );
+  }
+
+  @protected
+  Logs sse_decode_logs(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_logs = sse_decode_list_log(deserializer);
+    var var_totalCount = sse_decode_u_32(deserializer);
+    var var_strategy = sse_decode_opt_box_autoadd_search_strategy(deserializer);
+    return Logs(
+      logs: var_logs,
+      totalCount: var_totalCount,
+      strategy: var_strategy,
+    );
+  }
+
+  @protected
+  MatchSpan sse_decode_match_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_start = sse_decode_u_32(deserializer);
+    var var_end = sse_decode_u_32(deserializer);
+    return MatchSpan(field: var_field, start: var_start, end: var_end);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SearchStrategy? sse_decode_opt_box_autoadd_search_strategy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_search_strategy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  SearchMode sse_decode_search_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SearchMode.values[inner];
+  }
+
+  @protected
+  SearchOptions sse_decode_search_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_search_mode(deserializer);
+    var var_caseSensitive = sse_decode_bool(deserializer);
+    var var_wholeWord = sse_decode_bool(deserializer);
+    return SearchOptions(
+      mode: var_mode,
+      caseSensitive: var_caseSensitive,
+      wholeWord: var_wholeWord,
+    );
+  }
+
+  @protected
+  SearchStrategy sse_decode_search_strategy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SearchStrategy.values[inner];
+  }
+
+  @protected
+  SortKey sse_decode_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_column = sse_decode_String(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_sortType = sse_decode_sort_type(deserializer);
+    return SortKey(
+      column: var_column,
+      descending: var_descending,
+      sortType: var_sortType,
+    );
+  }
+
+  @protected
+  SortType sse_decode_sort_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SortType.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  ValueKind sse_decode_value_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ValueKind.values[inner];
+  }
+
+  @protected
+  View sse_decode_view(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_filterSql = sse_decode_String(deserializer);
+    var var_ftsQuery = sse_decode_String(deserializer);
+    var var_search = sse_decode_search_options(deserializer);
+    var var_sort = sse_decode_list_sort_key(deserializer);
+    var var_columns = sse_decode_list_String(deserializer);
+    return View(
+      name: var_name,
+      filterSql: var_filterSql,
+      ftsQuery: var_ftsQuery,
+      search: var_search,
+      sort: var_sort,
+      columns: var_columns,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_search_options(
+    SearchOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_search_options(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_search_strategy(
+    SearchStrategy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_search_strategy(self, serializer);
   }
 
   @protected
-  FileStatus sse_decode_file_status(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return FileStatus_Uninit();
-      case 1:
-        return FileStatus_Pending();
-      case 2:
-        return FileStatus_Complete();
-      case 3:
-        var var_field0 = sse_decode_String(deserializer);
-        return FileStatus_Error(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  List<Log> sse_decode_list_log(SseDeserializer deserializer) {
+  void sse_encode_cell_style(CellStyle self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Log>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_log(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_export_format(ExportFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_facet_value(FacetValue self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.value, serializer);
+    sse_encode_u_32(self.count, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
+  @protected
+  void sse_encode_file_status(FileStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FileStatus_Uninit():
+        sse_encode_i_32(0, serializer);
+      case FileStatus_Pending():
+        sse_encode_i_32(1, serializer);
+      case FileStatus_Complete():
+        sse_encode_i_32(2, serializer);
+      case FileStatus_Error(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Log sse_decode_log(SseDeserializer deserializer) {
+  void sse_encode_histogram(Histogram self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_fields = sse_decode_Map_String_String_None(deserializer);
-    return Log(id: var_id, fields: var_fields);
+    sse_encode_i_64(self.startMs, serializer);
+    sse_encode_i_64(self.bucketMs, serializer);
+    sse_encode_list_prim_u_32_strict(self.counts, serializer);
   }
 
   @protected
-  Logs sse_decode_logs(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_logs = sse_decode_list_log(deserializer);
-    var var_totalCount = sse_decode_u_32(deserializer);
-    return Logs(logs: var_logs, totalCount: var_totalCount);
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_inferred_field(InferredField self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_value_kind(self.kind, serializer);
+    sse_encode_f_64(self.fillRate, serializer);
+    sse_encode_bool(self.repeated, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_inferred_rules(InferredRules self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_String(self.rules, serializer);
+    sse_encode_list_inferred_field(self.fields, serializer);
+    sse_encode_opt_String(self.timeField, serializer);
+    sse_encode_u_32(self.sampledLines, serializer);
+    sse_encode_u_32(self.jsonLines, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_layout(Layout self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_list_layout_column(self.columns, serializer);
+    sse_encode_list_String(self.fields, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_layout_cell(LayoutCell self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.expr, serializer);
+    sse_encode_cell_style(self.style, serializer);
+    sse_encode_u_32(self.maxLines, serializer);
+    sse_encode_bool(self.ellipsis, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_layout_column(LayoutColumn self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_opt_box_autoadd_f_64(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.flex, serializer);
+    sse_encode_list_layout_cell(self.rows, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_facet_value(
+    List<FacetValue> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_facet_value(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
+  void sse_encode_list_inferred_field(
+    List<InferredField> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_inferred_field(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_layout_cell(
+    List<LayoutCell> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_layout_cell(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_file_status(FileStatus self, SseSerializer serializer) {
+  void sse_encode_list_layout_column(
+    List<LayoutColumn> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case FileStatus_Uninit():
-        sse_encode_i_32(0, serializer);
-      case FileStatus_Pending():
-        sse_encode_i_32(1, serializer);
-      case FileStatus_Complete():
-        sse_encode_i_32(2, serializer);
-      case FileStatus_Error(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_layout_column(item, serializer);
     }
   }
 
@@ -527,6 +1816,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_match_span(
+    List<MatchSpan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_match_span(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -549,11 +1860,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sort_key(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_view(List<View> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_view(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_log(Log self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self.id, serializer);
     sse_encode_Map_String_String_None(self.fields, serializer);
+    sse_encode_list_match_span(self.matches, serializer);
+    sse_encode_list_String(self.cells, serializer);
   }
 
   @protected
@@ -561,6 +1892,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_log(self.logs, serializer);
     sse_encode_u_32(self.totalCount, serializer);
+    sse_encode_opt_box_autoadd_search_strategy(self.strategy, serializer);
+  }
+
+  @protected
+  void sse_encode_match_span(MatchSpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.field, serializer);
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_u_32(self.end, serializer);
   }
 
   @protected
@@ -573,6 +1913,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_search_strategy(
+    SearchStrategy? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_search_strategy(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -583,6 +1969,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_search_mode(SearchMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_search_options(SearchOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_search_mode(self.mode, serializer);
+    sse_encode_bool(self.caseSensitive, serializer);
+    sse_encode_bool(self.wholeWord, serializer);
+  }
+
+  @protected
+  void sse_encode_search_strategy(
+    SearchStrategy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sort_key(SortKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.column, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_sort_type(self.sortType, serializer);
+  }
+
+  @protected
+  void sse_encode_sort_type(SortType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -601,14 +2024,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_value_kind(ValueKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_view(View self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.filterSql, serializer);
+    sse_encode_String(self.ftsQuery, serializer);
+    sse_encode_search_options(self.search, serializer);
+    sse_encode_list_sort_key(self.sort, serializer);
+    sse_encode_list_String(self.columns, serializer);
   }
 }
@@ -29,30 +29,138 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
+
+  @protected
+  SearchStrategy dco_decode_box_autoadd_search_strategy(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  CellStyle dco_decode_cell_style(dynamic raw);
+
+  @protected
+  ExportFormat dco_decode_export_format(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FacetValue dco_decode_facet_value(dynamic raw);
+
   @protected
   FileStatus dco_decode_file_status(dynamic raw);
 
+  @protected
+  Histogram dco_decode_histogram(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  InferredField dco_decode_inferred_field(dynamic raw);
+
+  @protected
+  InferredRules dco_decode_inferred_rules(dynamic raw);
+
+  @protected
+  Layout dco_decode_layout(dynamic raw);
+
+  @protected
+  LayoutCell dco_decode_layout_cell(dynamic raw);
+
+  @protected
+  LayoutColumn dco_decode_layout_column(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<FacetValue> dco_decode_list_facet_value(dynamic raw);
+
+  @protected
+  List<InferredField> dco_decode_list_inferred_field(dynamic raw);
+
+  @protected
+  List<LayoutCell> dco_decode_list_layout_cell(dynamic raw);
+
+  @protected
+  List<LayoutColumn> dco_decode_list_layout_column(dynamic raw);
+
   @protected
   List<Log> dco_decode_list_log(dynamic raw);
 
+  @protected
+  List<MatchSpan> dco_decode_list_match_span(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<SortKey> dco_decode_list_sort_key(dynamic raw);
+
+  @protected
+  List<View> dco_decode_list_view(dynamic raw);
+
   @protected
   Log dco_decode_log(dynamic raw);
 
   @protected
   Logs dco_decode_logs(dynamic raw);
 
+  @protected
+  MatchSpan dco_decode_match_span(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  SearchStrategy? dco_decode_opt_box_autoadd_search_strategy(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
+  @protected
+  SearchMode dco_decode_search_mode(dynamic raw);
+
+  @protected
+  SearchOptions dco_decode_search_options(dynamic raw);
+
+  @protected
+  SearchStrategy dco_decode_search_strategy(dynamic raw);
+
+  @protected
+  SortKey dco_decode_sort_key(dynamic raw);
+
+  @protected
+  SortType dco_decode_sort_type(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -62,6 +170,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  ValueKind dco_decode_value_kind(dynamic raw);
+
+  @protected
+  View dco_decode_view(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
@@ -73,12 +187,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  SearchOptions sse_decode_box_autoadd_search_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchStrategy sse_decode_box_autoadd_search_strategy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  CellStyle sse_decode_cell_style(SseDeserializer deserializer);
+
+  @protected
+  ExportFormat sse_decode_export_format(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FacetValue sse_decode_facet_value(SseDeserializer deserializer);
+
   @protected
   FileStatus sse_decode_file_status(SseDeserializer deserializer);
 
+  @protected
+  Histogram sse_decode_histogram(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  InferredField sse_decode_inferred_field(SseDeserializer deserializer);
+
+  @protected
+  InferredRules sse_decode_inferred_rules(SseDeserializer deserializer);
+
+  @protected
+  Layout sse_decode_layout(SseDeserializer deserializer);
+
+  @protected
+  LayoutCell sse_decode_layout_cell(SseDeserializer deserializer);
+
+  @protected
+  LayoutColumn sse_decode_layout_column(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FacetValue> sse_decode_list_facet_value(SseDeserializer deserializer);
+
+  @protected
+  List<InferredField> sse_decode_list_inferred_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LayoutCell> sse_decode_list_layout_cell(SseDeserializer deserializer);
+
+  @protected
+  List<LayoutColumn> sse_decode_list_layout_column(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<Log> sse_decode_list_log(SseDeserializer deserializer);
 
+  @protected
+  List<MatchSpan> sse_decode_list_match_span(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -87,20 +281,58 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer);
+
+  @protected
+  List<View> sse_decode_list_view(SseDeserializer deserializer);
+
   @protected
   Log sse_decode_log(SseDeserializer deserializer);
 
   @protected
   Logs sse_decode_logs(SseDeserializer deserializer);
 
+  @protected
+  MatchSpan sse_decode_match_span(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  SearchStrategy? sse_decode_opt_box_autoadd_search_strategy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
   @protected
   (String, String) sse_decode_record_string_string(
     SseDeserializer deserializer,
   );
 
+  @protected
+  SearchMode sse_decode_search_mode(SseDeserializer deserializer);
+
+  @protected
+  SearchOptions sse_decode_search_options(SseDeserializer deserializer);
+
+  @protected
+  SearchStrategy sse_decode_search_strategy(SseDeserializer deserializer);
+
+  @protected
+  SortKey sse_decode_sort_key(SseDeserializer deserializer);
+
+  @protected
+  SortType sse_decode_sort_type(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_32(SseDeserializer deserializer);
 
@@ -111,10 +343,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  ValueKind sse_decode_value_kind(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  View sse_decode_view(SseDeserializer deserializer);
 
   @protected
   void sse_encode_AnyhowException(
@@ -131,12 +363,108 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_search_options(
+    SearchOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_search_strategy(
+    SearchStrategy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cell_style(CellStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_format(ExportFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_facet_value(FacetValue self, SseSerializer serializer);
+
   @protected
   void sse_encode_file_status(FileStatus self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_histogram(Histogram self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_inferred_field(InferredField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_inferred_rules(InferredRules self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_layout(Layout self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_layout_cell(LayoutCell self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_layout_column(LayoutColumn self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_facet_value(
+    List<FacetValue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_inferred_field(
+    List<InferredField> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_layout_cell(
+    List<LayoutCell> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_layout_column(
+    List<LayoutColumn> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_log(List<Log> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_match_span(
+    List<MatchSpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -149,21 +477,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_view(List<View> self, SseSerializer serializer);
+
   @protected
   void sse_encode_log(Log self, SseSerializer serializer);
 
   @protected
   void sse_encode_logs(Logs self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_match_span(MatchSpan self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_search_strategy(
+    SearchStrategy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_search_mode(SearchMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_strategy(
+    SearchStrategy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sort_key(SortKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sort_type(SortType self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -174,10 +547,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_value_kind(ValueKind self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_view(View self, SseSerializer serializer);
 }
 
 // Section: wire_class
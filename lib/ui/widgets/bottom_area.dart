@@ -1,6 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:provider/provider.dart';
 import 'package:lkl2/log_provider.dart';
+import 'package:lkl2/src/rust/file.dart' show FacetValue;
 import 'package:lkl2/ui/widgets/log_list.dart';
 import 'package:lkl2/ui/widgets/log_render_engine.dart';
 
@@ -14,6 +15,7 @@ class BottomArea extends StatefulWidget {
 class _BottomAreaState extends State<BottomArea> {
   final TextEditingController _valueController = TextEditingController();
   final TextEditingController _searchController = TextEditingController();
+  final FocusNode _valueFocus = FocusNode();
 
   String? _selectedField;
   FilterMode _selectedMode = FilterMode.contains;
@@ -21,10 +23,27 @@ class _BottomAreaState extends State<BottomArea> {
   List<String> _fields = [];
   bool _isLoadingFields = true;
 
+  /// Values of [_selectedField] under the applied filters, suggested while
+  /// typing a filter value.
+  List<FacetValue> _facets = [];
+
   @override
   void initState() {
     super.initState();
     _loadFields();
+    // Reload on focus so the suggestions follow the filters applied since.
+    _valueFocus.addListener(() {
+      if (_valueFocus.hasFocus) _loadFacets();
+    });
+  }
+
+  Future<void> _loadFacets() async {
+    final field = _selectedField;
+    if (field == null) return;
+    final facets = await context.read<LogProvider>().getFacets(field);
+    if (mounted && field == _selectedField) {
+      setState(() => _facets = facets);
+    }
   }
 
   Future<void> _loadFields() async {
@@ -44,6 +63,7 @@ class _BottomAreaState extends State<BottomArea> {
   void dispose() {
     _valueController.dispose();
     _searchController.dispose();
+    _valueFocus.dispose();
     super.dispose();
   }
 
@@ -118,8 +138,13 @@ class _BottomAreaState extends State<BottomArea> {
                                   ),
                                 )
                                 .toList(),
-                            onChanged: (v) =>
-                                setState(() => _selectedField = v!),
+                            onChanged: (v) {
+                              setState(() {
+                                _selectedField = v!;
+                                _facets = [];
+                              });
+                              _loadFacets();
+                            },
                           ),
                   ),
                   const SizedBox(width: 8),
@@ -154,22 +179,76 @@ class _BottomAreaState extends State<BottomArea> {
                     ),
                   ),
                   const SizedBox(width: 8),
-                  // Value Input
+                  // Value Input, suggesting the field's most frequent values
                   Expanded(
                     flex: 2,
-                    child: TextField(
-                      controller: _valueController,
-                      decoration: const InputDecoration(
-                        labelText: 'Value',
-                        hintText: 'Filter value...',
-                        isDense: true,
-                        border: OutlineInputBorder(),
-                        contentPadding: EdgeInsets.symmetric(
-                          horizontal: 8,
-                          vertical: 8,
-                        ),
-                      ),
-                      onSubmitted: (_) => _addFilter(provider),
+                    child: RawAutocomplete<FacetValue>(
+                      textEditingController: _valueController,
+                      focusNode: _valueFocus,
+                      displayStringForOption: (f) => f.value,
+                      optionsBuilder: (value) {
+                        final text = value.text.toLowerCase();
+                        return _facets.where(
+                          (f) =>
+                              f.value.isNotEmpty &&
+                              f.value.toLowerCase().contains(text),
+                        );
+                      },
+                      fieldViewBuilder:
+                          (context, controller, focusNode, onSubmitted) =>
+                              TextField(
+                                controller: controller,
+                                focusNode: focusNode,
+                                decoration: const InputDecoration(
+                                  labelText: 'Value',
+                                  hintText: 'Filter value...',
+                                  isDense: true,
+                                  border: OutlineInputBorder(),
+                                  contentPadding: EdgeInsets.symmetric(
+                                    horizontal: 8,
+                                    vertical: 8,
+                                  ),
+                                ),
+                                onSubmitted: (_) => _addFilter(provider),
+                              ),
+                      optionsViewBuilder: (context, onSelected, options) =>
+                          Align(
+                            alignment: Alignment.topLeft,
+                            child: Material(
+                              elevation: 4,
+                              child: ConstrainedBox(
+                                constraints: const BoxConstraints(
+                                  maxHeight: 240,
+                                  maxWidth: 320,
+                                ),
+                                child: ListView(
+                                  padding: EdgeInsets.zero,
+                                  shrinkWrap: true,
+                                  children: options
+                                      .map(
+                                        (f) => ListTile(
+                                          dense: true,
+                                          title: Text(
+                                            f.value,
+                                            overflow: TextOverflow.ellipsis,
+                                            style: const TextStyle(
+                                              fontSize: 13,
+                                            ),
+                                          ),
+                                          trailing: Text(
+                                            "${f.count}",
+                                            style: const TextStyle(
+                                              fontSize: 12,
+                                            ),
+                                          ),
+                                          onTap: () => onSelected(f),
+                                        ),
+                                      )
+                                      .toList(),
+                                ),
+                              ),
+                            ),
+                          ),
                     ),
                   ),
                   const SizedBox(width: 4),
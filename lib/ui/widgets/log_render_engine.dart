@@ -1,5 +1,4 @@
 import 'package:flutter/material.dart';
-import 'package:flutter/services.dart';
 import 'package:lkl2/src/rust/file.dart';
 
 class LogRenderEngine {
@@ -13,14 +12,25 @@ class LogRenderEngine {
 
   static final Future<LogRenderEngine> shared = _load();
 
+  static const _lineNumberColumn = LayoutColumn(
+    width: 50,
+    rows: [
+      LayoutCell(
+        expr: r'$lineNumber',
+        style: CellStyle.meta,
+        maxLines: 1,
+        ellipsis: true,
+      ),
+    ],
+  );
+
   static Future<LogRenderEngine> _load() async {
     try {
-      // Load from the asset path defined in pubspec.yaml
-      final content = await rootBundle.loadString(
-        'rust/libparser/rules/lkl2.toml',
+      // Parsed and validated by libparser along with the rest of the rules
+      final layout = await getLayout();
+      return LogRenderEngine(
+        LogRenderConfig(layout.columns, fields: layout.fields),
       );
-      final config = LogRenderConfig.fromToml(content);
-      return LogRenderEngine(config);
     } catch (e) {
       debugPrint("Error loading log config: $e");
       return fallback;
@@ -32,20 +42,20 @@ class LogRenderEngine {
 
     // Prepend line number if enabled
     if (showLineNumbers) {
-      cells.add(
-        _buildColumn(
-          context,
-          log,
-          const LogColumn(
-            width: 50,
-            rows: [LogCell(expr: r'$lineNumber', style: 'meta')],
-          ),
-        ),
-      );
+      final lineNumber = log.fields['lineNumber'] ?? '';
+      cells.add(_buildColumn(context, [lineNumber], _lineNumberColumn));
     }
 
-    // Add configured columns
-    cells.addAll(config.columns.map((col) => _buildColumn(context, log, col)));
+    // Add configured columns; libparser renders their cells in layout order
+    var next = 0;
+    for (final col in config.columns) {
+      final texts = [
+        for (var i = next; i < next + col.rows.length; i++)
+          i < log.cells.length ? log.cells[i] : '',
+      ];
+      next += col.rows.length;
+      cells.add(_buildColumn(context, texts, col));
+    }
 
     if (cells.isEmpty) return [];
 
@@ -61,10 +71,15 @@ class LogRenderEngine {
     return spaced;
   }
 
-  Widget _buildColumn(BuildContext context, Log log, LogColumn col) {
-    final widgets = col.rows
-        .map((row) => _buildCell(context, log, row))
-        .toList();
+  Widget _buildColumn(
+    BuildContext context,
+    List<String> texts,
+    LayoutColumn col,
+  ) {
+    final widgets = [
+      for (var i = 0; i < col.rows.length; i++)
+        _buildCell(context, texts[i], col.rows[i]),
+    ];
 
     final child = col.rows.length == 1
         ? widgets.first
@@ -82,26 +97,23 @@ class LogRenderEngine {
     return Expanded(child: child);
   }
 
-  Widget _buildCell(BuildContext context, Log log, LogCell cell) {
-    final text = _evalExpr(log, cell.expr);
-    final maxLines = cell.maxLines ?? 1;
-    final overflow = cell.ellipsis == false ? null : TextOverflow.ellipsis;
+  Widget _buildCell(BuildContext context, String text, LayoutCell cell) {
+    final overflow = cell.ellipsis ? TextOverflow.ellipsis : null;
 
-    return _styledText(context, text, cell.style ?? 'text', maxLines, overflow);
+    return _styledText(context, text, cell.style, cell.maxLines, overflow);
   }
 
   Widget _styledText(
     BuildContext context,
     String text,
-    String styleToken,
+    CellStyle style,
     int maxLines,
     TextOverflow? overflow,
   ) {
-    final token = styleToken.toLowerCase();
     final base = Theme.of(context).textTheme.bodySmall ?? const TextStyle();
 
-    switch (token) {
-      case 'tag':
+    switch (style) {
+      case CellStyle.tag:
         return Container(
           padding: const EdgeInsets.symmetric(horizontal: 6, vertical: 2),
           decoration: BoxDecoration(
@@ -115,7 +127,7 @@ class LogRenderEngine {
             overflow: overflow,
           ),
         );
-      case 'colortag':
+      case CellStyle.colorTag:
         return Container(
           padding: const EdgeInsets.symmetric(horizontal: 6, vertical: 2),
           decoration: BoxDecoration(
@@ -132,257 +144,52 @@ class LogRenderEngine {
             overflow: overflow,
           ),
         );
-      case 'meta':
+      case CellStyle.meta:
         return Text(
           text,
           style: base.copyWith(color: Colors.grey, fontSize: 11),
           maxLines: maxLines,
           overflow: overflow,
         );
-      case 'text':
-      default:
+      case CellStyle.text:
         return Text(text, style: base, maxLines: maxLines, overflow: overflow);
     }
   }
-
-  String _evalExpr(Log log, String expr) {
-    final trimmed = expr.trim();
-    if (trimmed.isEmpty) {
-      return '';
-    }
-    if (trimmed.startsWith(r'$')) {
-      return _resolveField(log, trimmed.substring(1));
-    }
-    if (trimmed.startsWith('time(') && trimmed.endsWith(')')) {
-      final inner = trimmed.substring(5, trimmed.length - 1);
-      return _formatTime(_evalExpr(log, inner));
-    }
-    return trimmed;
-  }
-
-  String _resolveField(Log log, String key) {
-    return log.fields[key] ?? '';
-  }
-
-  // _formatKv removed as requested
-
-  String _formatTime(String raw) {
-    final value = raw.trim();
-    if (value.isEmpty) {
-      return '';
-    }
-    final asInt = int.tryParse(value);
-    DateTime? time;
-    if (asInt != null) {
-      final millis = value.length <= 10 ? asInt * 1000 : asInt;
-      time = DateTime.fromMillisecondsSinceEpoch(millis, isUtc: true).toLocal();
-    } else {
-      time = DateTime.tryParse(value)?.toLocal();
-    }
-    if (time == null) {
-      return value;
-    }
-    String two(int n) => n.toString().padLeft(2, '0');
-    return '${time.year}-${two(time.month)}-${two(time.day)} ${two(time.hour)}:${two(time.minute)}:${two(time.second)}';
-  }
 }
 
 class LogRenderConfig {
-  final List<LogColumn> columns;
+  final List<LayoutColumn> columns;
   final List<String> fields;
 
   const LogRenderConfig(this.columns, {this.fields = const []});
 
   factory LogRenderConfig.defaultConfig() {
-    return LogRenderConfig(
+    return const LogRenderConfig(
       [
-        LogColumn(
+        LayoutColumn(
           width: 180,
-          rows: [LogCell(expr: r'time($eventTime)', style: 'meta')],
+          rows: [
+            LayoutCell(
+              expr: r'time($eventTime)',
+              style: CellStyle.meta,
+              maxLines: 1,
+              ellipsis: true,
+            ),
+          ],
         ),
-        LogColumn(
+        LayoutColumn(
           flex: 3,
-          rows: [LogCell(expr: r'$eventName', style: 'text')],
+          rows: [
+            LayoutCell(
+              expr: r'$eventName',
+              style: CellStyle.text,
+              maxLines: 1,
+              ellipsis: true,
+            ),
+          ],
         ),
       ],
-      fields: ['eventTime', 'eventName'],
-    );
-  }
-
-  factory LogRenderConfig.fromToml(String content) {
-    return _parseConfig(content);
-  }
-}
-
-class LogColumn {
-  final double? width;
-  final int? flex;
-  final List<LogCell> rows;
-
-  const LogColumn({required this.rows, this.width, this.flex});
-}
-
-class LogCell {
-  final String expr;
-  final String? style;
-  final int? maxLines;
-  final bool? ellipsis;
-
-  const LogCell({required this.expr, this.style, this.maxLines, this.ellipsis});
-}
-
-LogRenderConfig _parseConfig(String content) {
-  final columns = <_ColumnBuilder>[];
-  final fields = <String>[];
-
-  _ColumnBuilder? currentCol;
-  _RowBuilder? currentRow;
-  String currentSection = '';
-
-  void flushRow() {
-    if (currentRow != null && currentCol != null) {
-      currentCol!.rows.add(currentRow!);
-      currentRow = null;
-    }
-  }
-
-  void flushCol() {
-    if (currentCol != null) {
-      flushRow();
-      columns.add(currentCol!);
-      currentCol = null;
-    }
-  }
-
-  for (final rawLine in content.split('\n')) {
-    final line = rawLine.trim();
-    if (line.isEmpty || line.startsWith('#')) {
-      continue;
-    }
-
-    if (line == '[logs]') {
-      flushCol();
-      currentSection = 'logs';
-      continue;
-    }
-
-    if (line == '[[col]]') {
-      flushCol();
-      currentSection = 'col';
-      currentCol = _ColumnBuilder();
-      continue;
-    }
-    if (line == '[[col.row]]') {
-      if (currentCol == null) {
-        continue;
-      }
-      flushRow();
-      currentSection = 'col.row';
-      currentRow = _RowBuilder();
-      continue;
-    }
-
-    final idx = line.indexOf('=');
-    if (idx == -1) {
-      continue;
-    }
-    final key = line.substring(0, idx).trim();
-    final value = _stripQuotes(line.substring(idx + 1).trim());
-
-    if (currentSection == 'logs') {
-      fields.add(key);
-    } else if (currentSection == 'col' || currentSection == 'col.row') {
-      if (currentRow != null) {
-        currentRow!.apply(key, value);
-      } else if (currentCol != null) {
-        currentCol!.apply(key, value);
-      }
-    }
-  }
-
-  flushCol();
-
-  return LogRenderConfig(
-    columns.map((c) => c.build()).where((c) => c.rows.isNotEmpty).toList(),
-    fields: fields,
-  );
-}
-
-class _ColumnBuilder {
-  double? width;
-  int? flex;
-  String expr = '';
-  String style = '';
-  final List<_RowBuilder> rows = [];
-
-  void apply(String key, String value) {
-    switch (key) {
-      case 'width':
-        width = double.tryParse(value);
-        break;
-      case 'flex':
-        flex = int.tryParse(value);
-        break;
-      case 'expr':
-        expr = value;
-        break;
-      case 'style':
-        style = value;
-        break;
-    }
-  }
-
-  LogColumn build() {
-    final builtRows = rows.isNotEmpty
-        ? rows.map((r) => r.build()).toList()
-        : [LogCell(expr: expr, style: style.isEmpty ? null : style)];
-    final filtered = builtRows.where((r) => r.expr.trim().isNotEmpty).toList();
-    return LogColumn(width: width, flex: flex, rows: filtered);
-  }
-}
-
-class _RowBuilder {
-  String expr = '';
-  String style = '';
-  int? maxLines;
-  bool? ellipsis;
-
-  void apply(String key, String value) {
-    switch (key) {
-      case 'expr':
-        expr = value;
-        break;
-      case 'style':
-        style = value;
-        break;
-      case 'lines':
-      case 'maxLines':
-        maxLines = int.tryParse(value);
-        break;
-      case 'ellipsis':
-        ellipsis = value.toLowerCase() == 'true';
-        break;
-    }
-  }
-
-  LogCell build() {
-    return LogCell(
-      expr: expr,
-      style: style.isEmpty ? null : style,
-      maxLines: maxLines,
-      ellipsis: ellipsis,
+      fields: ['eventName', 'eventTime'],
     );
   }
 }
-
-String _stripQuotes(String value) {
-  final trimmed = value.trim();
-  if (trimmed.length >= 2) {
-    final first = trimmed[0];
-    final last = trimmed[trimmed.length - 1];
-    if ((first == '"' && last == '"') || (first == "'" && last == "'")) {
-      return trimmed.substring(1, trimmed.length - 1);
-    }
-  }
-  return trimmed;
-}
@@ -1,7 +1,10 @@
 use anyhow::Result;
-use libparser::{Config, Engine};
+use libparser::{
+    Config, DecodeMode, Engine, LoadOptions, LogQuery, LogRecord, RawStorage, ReaderBackend,
+};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Mutex;
 use std::thread;
 
@@ -11,12 +14,183 @@ use std::thread;
 pub struct Log {
     pub id: u32,
     pub fields: HashMap<String, String>,
+    /// fts_query 的命中位置，用于高亮；没有全文搜索时为空
+    pub matches: Vec<MatchSpan>,
+    /// 按 get_layout 中各列、各单元格的顺序渲染好的显示文本
+    pub cells: Vec<String>,
+}
+
+/// field 为字段名或 "raw"，[start, end) 为字符偏移
+#[derive(Clone, Debug)]
+pub struct MatchSpan {
+    pub field: String,
+    pub start: u32,
+    pub end: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Logs {
     pub logs: Vec<Log>,
     pub total_count: u32,
+    /// 全文搜索的执行方式；没有全文搜索时为空
+    pub strategy: Option<SearchStrategy>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchStrategy {
+    /// 走 trigram 索引
+    Trigram,
+    /// 有少于 3 个字符的词或为正则，逐行扫描
+    Scan,
+}
+
+#[derive(Clone, Debug)]
+pub struct FacetValue {
+    pub value: String,
+    pub count: u32,
+}
+
+/// counts[i] 为 [start_ms + i * bucket_ms, start_ms + (i + 1) * bucket_ms) 内的日志数
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    pub start_ms: i64,
+    pub bucket_ms: i64,
+    pub counts: Vec<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortType {
+    Text,
+    Integer,
+    Real,
+}
+
+/// fts_query 的解释方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SearchMode {
+    /// 整体作为子串
+    Phrase,
+    /// 多个词用 AND / OR / NOT / 括号组合，"..." 为短语，相邻的词默认 AND
+    Boolean,
+    /// 正则表达式 (Rust regex 语法)
+    Regex,
+    /// 等同于 Phrase + case_sensitive
+    Literal,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SearchOptions {
+    pub mode: SearchMode,
+    /// 区分大小写，否则忽略大小写
+    pub case_sensitive: bool,
+    /// 全词匹配：命中前后不能紧挨字母、数字或下划线
+    pub whole_word: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    pub column: String,
+    pub descending: bool,
+    pub sort_type: SortType,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    /// 每行一个 JSON 对象 (id 及各字段)
+    Jsonl,
+    /// 原始日志行
+    JsonlRaw,
+    Parquet,
+    /// 按日志列表布局渲染的显示文本 (CSV，每个单元格一列)
+    Cells,
+}
+
+/// 规则文件中 [[view]] 定义的预设视图
+/// columns: 显示的列，为空则全部显示
+#[derive(Clone, Debug)]
+pub struct View {
+    pub name: String,
+    pub filter_sql: String,
+    pub fts_query: String,
+    pub search: SearchOptions,
+    pub sort: Vec<SortKey>,
+    pub columns: Vec<String>,
+}
+
+/// 规则文件中 [[col]] 定义的日志列表布局
+/// fields: [logs] 中定义的字段，按名称排序
+#[derive(Clone, Debug)]
+pub struct Layout {
+    pub columns: Vec<LayoutColumn>,
+    pub fields: Vec<String>,
+}
+
+/// 日志列表中的一列，rows 自上而下排列
+/// width: 固定宽度 (逻辑像素)；flex: 按比例分配剩余宽度；都为空时 flex 为 1
+#[derive(Clone, Debug)]
+pub struct LayoutColumn {
+    pub width: Option<f64>,
+    pub flex: Option<u32>,
+    pub rows: Vec<LayoutCell>,
+}
+
+/// expr: 显示表达式，如 "$eventName"、"time($eventTime)"
+/// ellipsis: 超出 max_lines 时是否显示省略号
+#[derive(Clone, Debug)]
+pub struct LayoutCell {
+    pub expr: String,
+    pub style: CellStyle,
+    pub max_lines: u32,
+    pub ellipsis: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CellStyle {
+    Text,
+    /// 灰色小字
+    Meta,
+    /// 灰色底的小标签
+    Tag,
+    /// 主题色底的小标签
+    ColorTag,
+}
+
+/// 根据日志文件前若干行推断出的规则
+/// rules: 建议的规则文件内容 (lkl2.toml 格式)；time_field: 建议的 [time] 字段
+/// json_lines: 采样的非空行中为 JSON 对象的行数
+#[derive(Clone, Debug)]
+pub struct InferredRules {
+    pub rules: String,
+    pub fields: Vec<InferredField>,
+    pub time_field: Option<String>,
+    pub sampled_lines: u32,
+    pub json_lines: u32,
+}
+
+/// name: 建议的列名；path: [logs] 中的路径，如 "$line.Event.eventName"
+/// fill_rate: 有非 null 值的 JSON 行占比 (0~1)；repeated: 取值只有少数几种且重复出现
+#[derive(Clone, Debug)]
+pub struct InferredField {
+    pub name: String,
+    pub path: String,
+    pub kind: ValueKind,
+    pub fill_rate: f64,
+    pub repeated: bool,
+}
+
+/// 采样中该路径上值的 JSON 类型；null 不计入，整数与小数并存时为 Float
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueKind {
+    /// 只出现过 null
+    Null,
+    Bool,
+    Integer,
+    Float,
+    String,
+    Array,
+    /// 多种类型并存
+    Mixed,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -27,6 +201,132 @@ pub enum FileStatus {
     Error(String),
 }
 
+impl From<SortKey> for libparser::SortKey {
+    fn from(key: SortKey) -> Self {
+        let sort_type = match key.sort_type {
+            SortType::Text => libparser::SortType::Text,
+            SortType::Integer => libparser::SortType::Integer,
+            SortType::Real => libparser::SortType::Real,
+        };
+        libparser::SortKey {
+            column: key.column,
+            descending: key.descending,
+            sort_type,
+        }
+    }
+}
+
+impl From<SearchMode> for libparser::SearchMode {
+    fn from(mode: SearchMode) -> Self {
+        match mode {
+            SearchMode::Phrase => libparser::SearchMode::Phrase,
+            SearchMode::Boolean => libparser::SearchMode::Boolean,
+            SearchMode::Regex => libparser::SearchMode::Regex,
+            SearchMode::Literal => libparser::SearchMode::Literal,
+        }
+    }
+}
+
+impl From<SearchOptions> for libparser::SearchOptions {
+    fn from(options: SearchOptions) -> Self {
+        libparser::SearchOptions {
+            mode: options.mode.into(),
+            case_sensitive: options.case_sensitive,
+            whole_word: options.whole_word,
+        }
+    }
+}
+
+impl From<libparser::SortKey> for SortKey {
+    fn from(key: libparser::SortKey) -> Self {
+        let sort_type = match key.sort_type {
+            libparser::SortType::Text => SortType::Text,
+            libparser::SortType::Integer => SortType::Integer,
+            libparser::SortType::Real => SortType::Real,
+        };
+        SortKey {
+            column: key.column,
+            descending: key.descending,
+            sort_type,
+        }
+    }
+}
+
+impl From<libparser::SearchOptions> for SearchOptions {
+    fn from(options: libparser::SearchOptions) -> Self {
+        let mode = match options.mode {
+            libparser::SearchMode::Phrase => SearchMode::Phrase,
+            libparser::SearchMode::Boolean => SearchMode::Boolean,
+            libparser::SearchMode::Regex => SearchMode::Regex,
+            libparser::SearchMode::Literal => SearchMode::Literal,
+        };
+        SearchOptions {
+            mode,
+            case_sensitive: options.case_sensitive,
+            whole_word: options.whole_word,
+        }
+    }
+}
+
+impl From<ExportFormat> for libparser::ExportFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Csv => libparser::ExportFormat::Csv,
+            ExportFormat::Jsonl => libparser::ExportFormat::Jsonl,
+            ExportFormat::JsonlRaw => libparser::ExportFormat::JsonlRaw,
+            ExportFormat::Parquet => libparser::ExportFormat::Parquet,
+            ExportFormat::Cells => libparser::ExportFormat::Cells,
+        }
+    }
+}
+
+impl From<libparser::ColumnConfig> for LayoutColumn {
+    fn from(col: libparser::ColumnConfig) -> Self {
+        LayoutColumn {
+            width: col.width,
+            flex: col.flex,
+            rows: col.rows.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<libparser::InferredField> for InferredField {
+    fn from(field: libparser::InferredField) -> Self {
+        InferredField {
+            name: field.name,
+            path: field.path,
+            kind: match field.kind {
+                libparser::ValueKind::Null => ValueKind::Null,
+                libparser::ValueKind::Bool => ValueKind::Bool,
+                libparser::ValueKind::Integer => ValueKind::Integer,
+                libparser::ValueKind::Float => ValueKind::Float,
+                libparser::ValueKind::String => ValueKind::String,
+                libparser::ValueKind::Array => ValueKind::Array,
+                libparser::ValueKind::Mixed => ValueKind::Mixed,
+            },
+            fill_rate: field.fill_rate,
+            repeated: field.repeated,
+        }
+    }
+}
+
+impl From<libparser::CellConfig> for LayoutCell {
+    fn from(cell: libparser::CellConfig) -> Self {
+        let style = match cell.style {
+            libparser::CellStyle::Text => CellStyle::Text,
+            libparser::CellStyle::Meta => CellStyle::Meta,
+            libparser::CellStyle::Tag => CellStyle::Tag,
+            libparser::CellStyle::ColorTag => CellStyle::ColorTag,
+        };
+        LayoutCell {
+            expr: cell.expr.to_string(),
+            style,
+            max_lines: cell.max_lines,
+            ellipsis: cell.ellipsis,
+        }
+    }
+}
+
 // Global State
 struct AppState {
     engine: Option<Engine>,
@@ -40,8 +340,13 @@ static STATE: Lazy<Mutex<AppState>> = Lazy::new(|| {
     })
 });
 
+/// 当前导出已写入的行数
+static EXPORT_PROGRESS: AtomicU32 = AtomicU32::new(0);
+
 /// 1.1 dart打开文件 -> rust后台开启线程处理文件
-pub fn open_file(path: String) {
+/// map_file: 为 true 时以内存映射读取文件，原始行不入库只记录偏移 (省内存)；
+/// 打开期间文件被截断会导致进程崩溃 (SIGBUS)，只用于不再写入的文件
+pub fn open_file(path: String, map_file: bool) {
     // Set status to Pending
     {
         let mut state = STATE.lock().unwrap();
@@ -53,7 +358,17 @@ pub fn open_file(path: String) {
         let res = (|| -> Result<Engine> {
             let config = Config::load()?;
             let mut engine = Engine::new(config)?;
-            engine.load_file(&path)?;
+            // 保留原始字节，get_log_raw 可返回与文件完全一致的内容
+            let mut options = LoadOptions {
+                decode: DecodeMode::Raw,
+                ..Default::default()
+            };
+            if map_file {
+                // 原始行不入库，只记录其在文件中的偏移，按需从文件读取
+                options.storage = RawStorage::Offsets;
+                options.reader = ReaderBackend::Mmap;
+            }
+            engine.load_file_with(&path, &options)?;
             Ok(engine)
         })();
 
@@ -79,110 +394,265 @@ pub fn get_file_status() -> FileStatus {
 /// 1.3 dart查询日志 （这里不返回详细信息)
 /// filter_sql: SQL WHERE clause fragment (e.g., "eventName = 'Error'")
 /// fts_query: Full text search query
+/// search: fts_query 的解释方式及大小写、全词匹配选项
+/// sort: 排序字段，按优先级排列；为空则按加载顺序
 pub fn get_logs(
     filter_sql: String,
     fts_query: String,
+    search: SearchOptions,
+    sort: Vec<SortKey>,
     limit: u32,
     offset: u32,
 ) -> Result<Logs> {
     let state = STATE.lock().unwrap();
     if let Some(engine) = &state.engine {
-        // 1. Build Base Query
-        let mut where_clauses = Vec::new();
-        
-        // FTS filter
-        if !fts_query.trim().is_empty() {
-            // Using subquery for FTS
-            // "id IN (SELECT rowid FROM logs_fts WHERE logs_fts MATCH 'query')"
-            // Use phrase search to avoid syntax errors with special characters
-            // Escape double quotes and single quotes
-            let escaped_query = fts_query.replace("'", "''").replace("\"", "\"\"");
-            where_clauses.push(format!("id IN (SELECT rowid FROM logs_fts WHERE logs_fts MATCH '\"{}\"')", escaped_query));
-        }
-        
-        // Normal filter
-        if !filter_sql.trim().is_empty() {
-            where_clauses.push(format!("({})", filter_sql));
-        }
-
-        let where_str = if where_clauses.is_empty() {
-            "".to_string()
-        } else {
-            format!("WHERE {}", where_clauses.join(" AND "))
-        };
-
-        // 2. Get Count
-        let count_query = format!("SELECT COUNT(*) FROM logs {}", where_str);
-        // We use engine.execute_query which returns string results. 
-        // Ideally libparser should expose a way to get raw values or we parse the string.
-        let count_res = engine.execute_query(&count_query)?;
-        let total_count: u32 = if !count_res.rows.is_empty() && !count_res.rows[0].is_empty() {
-             count_res.rows[0][0].parse().unwrap_or(0)
-        } else {
-            0
+        let query = LogQuery {
+            filter_sql,
+            fts_query,
+            search_options: search.into(),
+            sort: sort.into_iter().map(Into::into).collect(),
+            limit,
+            offset,
         };
-
-        // 3. Get Data
-        // We need to select columns excluding 'raw'
-        let columns = engine.columns(); 
-        // columns() returns ["id", "raw", "col1", "col2"...]
-        // We want to select "id", "col1", "col2"...
-        let select_cols: Vec<String> = columns.iter()
-            .filter(|c| c.as_str() != "raw")
-            .cloned()
-            .collect();
-        
-        let select_str = select_cols.join(", ");
-        
-        let data_query = format!(
-            "SELECT {} FROM logs {} LIMIT {} OFFSET {}",
-            select_str, where_str, limit, offset
-        );
-        
-        let query_res = engine.execute_query(&data_query)?;
-        
-        // Map to Logs
-        let mut logs = Vec::new();
-        let headers = query_res.headers; // These should match select_cols
-        
-        for row in query_res.rows {
-            let mut fields = HashMap::new();
-            let mut id = 0;
-            
-            for (i, val) in row.iter().enumerate() {
-                if i < headers.len() {
-                    let col_name = &headers[i];
-                    if col_name == "id" {
-                        id = val.parse().unwrap_or(0);
-                    } else {
-                        fields.insert(col_name.clone(), val.clone());
-                    }
-                }
-            }
-            logs.push(Log { id, fields });
-        }
-        
-        Ok(Logs { logs, total_count })
+        let page = engine.get_logs(&query)?;
+        let total_count = page.total_count as u32;
+        let strategy = page.strategy;
+        let logs = page.into_records().into_iter().map(to_log).collect();
+        Ok(Logs {
+            logs,
+            total_count,
+            strategy: strategy.map(|s| match s {
+                libparser::SearchStrategy::Trigram => SearchStrategy::Trigram,
+                libparser::SearchStrategy::Scan => SearchStrategy::Scan,
+            }),
+        })
     } else {
         Ok(Logs {
             logs: vec![],
             total_count: 0,
+            strategy: None,
         })
     }
 }
 
 /// 1.4 dart查询特定日志的详细信息
+/// 非 UTF-8 字节显示为 �，需要原始字节时用 get_log_raw
 pub fn get_log_detail(id: u32) -> Result<Option<String>> {
     let state = STATE.lock().unwrap();
     if let Some(engine) = &state.engine {
-        let query = format!("SELECT raw FROM logs WHERE id = {}", id);
-        let res = engine.execute_query(&query)?;
-        if !res.rows.is_empty() && !res.rows[0].is_empty() {
-            Ok(Some(res.rows[0][0].clone()))
-        } else {
-            Ok(None)
-        }
+        let raw = engine.get_raw(id.into())?;
+        Ok(raw.map(|bytes| String::from_utf8_lossy(&bytes).into_owned()))
     } else {
         Ok(None)
     }
 }
+
+/// 1.4.1 dart查询日志在文件中的原始字节 (不含换行符)
+pub fn get_log_raw(id: u32) -> Result<Option<Vec<u8>>> {
+    let state = STATE.lock().unwrap();
+    if let Some(engine) = &state.engine {
+        engine.get_raw(id.into())
+    } else {
+        Ok(None)
+    }
+}
+
+/// 1.5 dart查询某条日志前后的上下文 (类似 grep -C)
+/// same_field: 只看与该日志此字段值相同的日志 (例如 "sourceNodeId")，为空则不限制
+pub fn get_context(
+    id: u32,
+    before: u32,
+    after: u32,
+    same_field: Option<String>,
+) -> Result<Vec<Log>> {
+    let state = STATE.lock().unwrap();
+    if let Some(engine) = &state.engine {
+        let res = engine.get_context(id.into(), before, after, same_field.as_deref())?;
+        Ok(engine.records(res).into_iter().map(to_log).collect())
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// 1.6 dart查询某个字段在当前过滤条件下的取值分布 (按出现次数降序)
+/// filter_sql/fts_query/search: 同 get_logs
+/// limit: 最多返回多少个取值
+pub fn get_facets(
+    field: String,
+    filter_sql: String,
+    fts_query: String,
+    search: SearchOptions,
+    limit: u32,
+) -> Result<Vec<FacetValue>> {
+    let state = STATE.lock().unwrap();
+    if let Some(engine) = &state.engine {
+        let query = LogQuery {
+            filter_sql,
+            fts_query,
+            search_options: search.into(),
+            ..Default::default()
+        };
+        let facets = engine.get_facets(&field, &query, limit)?;
+        Ok(facets
+            .into_iter()
+            .map(|f| FacetValue {
+                value: f.value,
+                count: f.count as u32,
+            })
+            .collect())
+    } else {
+        Ok(vec![])
+    }
+}
+
+/// 1.7 dart查询当前过滤条件下日志的时间分布 (按 ts 列分桶)
+/// buckets: 期望的桶数量，实际桶宽会取整到 1s/1m/1h 等
+/// 选中时间范围后可用 filter_sql "ts >= a AND ts < b" 过滤
+pub fn get_histogram(
+    filter_sql: String,
+    fts_query: String,
+    search: SearchOptions,
+    buckets: u32,
+) -> Result<Histogram> {
+    let state = STATE.lock().unwrap();
+    if let Some(engine) = &state.engine {
+        let query = LogQuery {
+            filter_sql,
+            fts_query,
+            search_options: search.into(),
+            ..Default::default()
+        };
+        let histogram = engine.get_histogram(&query, buckets)?;
+        Ok(Histogram {
+            start_ms: histogram.start_ms,
+            bucket_ms: histogram.bucket_ms,
+            counts: histogram.counts.into_iter().map(|c| c as u32).collect(),
+        })
+    } else {
+        Ok(Histogram {
+            start_ms: 0,
+            bucket_ms: 0,
+            counts: vec![],
+        })
+    }
+}
+
+/// 1.8 dart查询规则文件中的预设视图，选中后用其参数调用 get_logs
+pub fn list_views() -> Result<Vec<View>> {
+    let config = Config::load()?;
+    Ok(config
+        .views
+        .into_iter()
+        .map(|v| View {
+            name: v.name,
+            filter_sql: v.filter,
+            fts_query: v.fts,
+            search: v.search.into(),
+            sort: v.sort.into_iter().map(Into::into).collect(),
+            columns: v.columns,
+        })
+        .collect())
+}
+
+/// 1.9 dart导出当前过滤条件下的全部日志 (忽略分页, 按 sort 排序)，返回导出的行数
+/// 导出期间可调用 get_export_progress 查询进度
+pub fn export_logs(
+    path: String,
+    format: ExportFormat,
+    filter_sql: String,
+    fts_query: String,
+    search: SearchOptions,
+    sort: Vec<SortKey>,
+) -> Result<u32> {
+    let engine = export_reader()?;
+    let query = LogQuery {
+        filter_sql,
+        fts_query,
+        search_options: search.into(),
+        sort: sort.into_iter().map(Into::into).collect(),
+        ..Default::default()
+    };
+    EXPORT_PROGRESS.store(0, Ordering::Relaxed);
+    let rows = engine.export_logs(&query, format.into(), &path, |rows| {
+        EXPORT_PROGRESS.store(rows as u32, Ordering::Relaxed)
+    })?;
+    Ok(rows as u32)
+}
+
+/// 1.10 dart查询导出进度 (已写入的行数)
+pub fn get_export_progress() -> u32 {
+    EXPORT_PROGRESS.load(Ordering::Relaxed)
+}
+
+/// 1.11 dart将当前过滤条件下日志的原始行按原顺序导出为日志文件 (字节不变)，返回导出的行数
+/// gzip: 是否压缩为 .gz；导出期间可调用 get_export_progress 查询进度
+pub fn export_raw_lines(
+    path: String,
+    gzip: bool,
+    filter_sql: String,
+    fts_query: String,
+    search: SearchOptions,
+) -> Result<u32> {
+    let engine = export_reader()?;
+    let query = LogQuery {
+        filter_sql,
+        fts_query,
+        search_options: search.into(),
+        ..Default::default()
+    };
+    EXPORT_PROGRESS.store(0, Ordering::Relaxed);
+    let rows = engine.export_raw(&query, &path, gzip, |rows| {
+        EXPORT_PROGRESS.store(rows as u32, Ordering::Relaxed)
+    })?;
+    Ok(rows as u32)
+}
+
+/// 1.12 dart查询规则文件中的日志列表布局 ([[col]]) 及可过滤的字段
+pub fn get_layout() -> Result<Layout> {
+    let config = Config::load()?;
+    let mut fields: Vec<String> = config.logs.keys().cloned().collect();
+    fields.sort();
+    Ok(Layout {
+        columns: config.layout.into_iter().map(Into::into).collect(),
+        fields,
+    })
+}
+
+/// 1.13 dart根据文件前 sample_lines 行推断字段，生成建议的规则文件 (不需要先打开文件)
+pub fn infer_rules(path: String, sample_lines: u32) -> Result<InferredRules> {
+    let lines = libparser::read_sample(&path, sample_lines as usize)?;
+    let schema = libparser::infer_schema(&lines);
+    Ok(InferredRules {
+        rules: schema.to_rules(),
+        fields: schema.fields.into_iter().map(Into::into).collect(),
+        time_field: schema.time_field,
+        sampled_lines: schema.sampled_lines as u32,
+        json_lines: schema.json_lines as u32,
+    })
+}
+
+/// 导出使用的只读引擎: 只在创建时持有 STATE 锁，导出期间其他接口不被阻塞
+fn export_reader() -> Result<Engine> {
+    let state = STATE.lock().unwrap();
+    let Some(engine) = &state.engine else {
+        anyhow::bail!("No file loaded");
+    };
+    engine.reader()
+}
+
+fn to_log(record: LogRecord) -> Log {
+    Log {
+        id: record.id as u32,
+        fields: record.fields,
+        matches: record
+            .matches
+            .into_iter()
+            .map(|m| MatchSpan {
+                field: m.column,
+                start: m.start as u32,
+                end: m.end as u32,
+            })
+            .collect(),
+        cells: record.cells,
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 23399159;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1695968060;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__file__get_file_status_impl(
+fn wire__crate__file__export_logs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__file__get_file_status_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_file_status",
+            debug_name: "export_logs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,17 +67,32 @@ fn wire__crate__file__get_file_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_format = <crate::file::ExportFormat>::sse_decode(&mut deserializer);
+            let api_filter_sql = <String>::sse_decode(&mut deserializer);
+            let api_fts_query = <String>::sse_decode(&mut deserializer);
+            let api_search = <crate::file::SearchOptions>::sse_decode(&mut deserializer);
+            let api_sort = <Vec<crate::file::SortKey>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::file::get_file_status())?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::export_logs(
+                            api_path,
+                            api_format,
+                            api_filter_sql,
+                            api_fts_query,
+                            api_search,
+                            api_sort,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__file__get_log_detail_impl(
+fn wire__crate__file__export_raw_lines_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -85,7 +100,52 @@ fn wire__crate__file__get_log_detail_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_log_detail",
+            debug_name: "export_raw_lines",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_gzip = <bool>::sse_decode(&mut deserializer);
+            let api_filter_sql = <String>::sse_decode(&mut deserializer);
+            let api_fts_query = <String>::sse_decode(&mut deserializer);
+            let api_search = <crate::file::SearchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::export_raw_lines(
+                            api_path,
+                            api_gzip,
+                            api_filter_sql,
+                            api_fts_query,
+                            api_search,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__file__get_context_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_context",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -100,11 +160,19 @@ fn wire__crate__file__get_log_detail_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_id = <u32>::sse_decode(&mut deserializer);
+            let api_before = <u32>::sse_decode(&mut deserializer);
+            let api_after = <u32>::sse_decode(&mut deserializer);
+            let api_same_field = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::file::get_log_detail(api_id)?;
+                        let output_ok = crate::file::get_context(
+                            api_id,
+                            api_before,
+                            api_after,
+                            api_same_field,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -112,7 +180,7 @@ fn wire__crate__file__get_log_detail_impl(
         },
     )
 }
-fn wire__crate__file__get_logs_impl(
+fn wire__crate__file__get_export_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -120,7 +188,39 @@ fn wire__crate__file__get_logs_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_logs",
+            debug_name: "get_export_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::file::get_export_progress())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__file__get_facets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_facets",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -134,19 +234,21 @@ fn wire__crate__file__get_logs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_field = <String>::sse_decode(&mut deserializer);
             let api_filter_sql = <String>::sse_decode(&mut deserializer);
             let api_fts_query = <String>::sse_decode(&mut deserializer);
+            let api_search = <crate::file::SearchOptions>::sse_decode(&mut deserializer);
             let api_limit = <u32>::sse_decode(&mut deserializer);
-            let api_offset = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::file::get_logs(
+                        let output_ok = crate::file::get_facets(
+                            api_field,
                             api_filter_sql,
                             api_fts_query,
+                            api_search,
                             api_limit,
-                            api_offset,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -155,7 +257,7 @@ fn wire__crate__file__get_logs_impl(
         },
     )
 }
-fn wire__crate__file__open_file_impl(
+fn wire__crate__file__get_file_status_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -163,7 +265,7 @@ fn wire__crate__file__open_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "open_file",
+            debug_name: "get_file_status",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -177,102 +279,668 @@ fn wire__crate__file__open_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::file::open_file(api_path);
-                    })?;
+                    let output_ok = Result::<_, ()>::Ok(crate::file::get_file_status())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::file::FileStatus {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::file::FileStatus::Uninit;
-            }
-            1 => {
-                return crate::file::FileStatus::Pending;
-            }
-            2 => {
-                return crate::file::FileStatus::Complete;
+fn wire__crate__file__get_histogram_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_histogram",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter_sql = <String>::sse_decode(&mut deserializer);
+            let api_fts_query = <String>::sse_decode(&mut deserializer);
+            let api_search = <crate::file::SearchOptions>::sse_decode(&mut deserializer);
+            let api_buckets = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::get_histogram(
+                            api_filter_sql,
+                            api_fts_query,
+                            api_search,
+                            api_buckets,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::file::FileStatus::Error(var_field0);
+        },
+    )
+}
+fn wire__crate__file__get_layout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_layout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::get_layout()?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
-            _ => {
-                unimplemented!("");
+        },
+    )
+}
+fn wire__crate__file__get_log_detail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_log_detail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::get_log_detail(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
-        }
-    }
+        },
+    )
 }
-
-impl SseDecode for Vec<crate::file::Log> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::file::Log>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+fn wire__crate__file__get_log_raw_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_log_raw",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::get_log_raw(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__file__get_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter_sql = <String>::sse_decode(&mut deserializer);
+            let api_fts_query = <String>::sse_decode(&mut deserializer);
+            let api_search = <crate::file::SearchOptions>::sse_decode(&mut deserializer);
+            let api_sort = <Vec<crate::file::SortKey>>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::get_logs(
+                            api_filter_sql,
+                            api_fts_query,
+                            api_search,
+                            api_sort,
+                            api_limit,
+                            api_offset,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__file__infer_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "infer_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_sample_lines = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::infer_rules(api_path, api_sample_lines)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__file__list_views_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_views",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::file::list_views()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__file__open_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_map_file = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::file::open_file(api_path, api_map_file);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::file::CellStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::CellStyle::Text,
+            1 => crate::file::CellStyle::Meta,
+            2 => crate::file::CellStyle::Tag,
+            3 => crate::file::CellStyle::ColorTag,
+            _ => unreachable!("Invalid variant for CellStyle: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::file::ExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::ExportFormat::Csv,
+            1 => crate::file::ExportFormat::Jsonl,
+            2 => crate::file::ExportFormat::JsonlRaw,
+            3 => crate::file::ExportFormat::Parquet,
+            4 => crate::file::ExportFormat::Cells,
+            _ => unreachable!("Invalid variant for ExportFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::file::FacetValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_value = <String>::sse_decode(deserializer);
+        let mut var_count = <u32>::sse_decode(deserializer);
+        return crate::file::FacetValue {
+            value: var_value,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::file::FileStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::file::FileStatus::Uninit;
+            }
+            1 => {
+                return crate::file::FileStatus::Pending;
+            }
+            2 => {
+                return crate::file::FileStatus::Complete;
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::file::FileStatus::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::file::Histogram {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <i64>::sse_decode(deserializer);
+        let mut var_bucketMs = <i64>::sse_decode(deserializer);
+        let mut var_counts = <Vec<u32>>::sse_decode(deserializer);
+        return crate::file::Histogram {
+            start_ms: var_startMs,
+            bucket_ms: var_bucketMs,
+            counts: var_counts,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::file::InferredField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::file::ValueKind>::sse_decode(deserializer);
+        let mut var_fillRate = <f64>::sse_decode(deserializer);
+        let mut var_repeated = <bool>::sse_decode(deserializer);
+        return crate::file::InferredField {
+            name: var_name,
+            path: var_path,
+            kind: var_kind,
+            fill_rate: var_fillRate,
+            repeated: var_repeated,
+        };
+    }
+}
+
+impl SseDecode for crate::file::InferredRules {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rules = <String>::sse_decode(deserializer);
+        let mut var_fields = <Vec<crate::file::InferredField>>::sse_decode(deserializer);
+        let mut var_timeField = <Option<String>>::sse_decode(deserializer);
+        let mut var_sampledLines = <u32>::sse_decode(deserializer);
+        let mut var_jsonLines = <u32>::sse_decode(deserializer);
+        return crate::file::InferredRules {
+            rules: var_rules,
+            fields: var_fields,
+            time_field: var_timeField,
+            sampled_lines: var_sampledLines,
+            json_lines: var_jsonLines,
+        };
+    }
+}
+
+impl SseDecode for crate::file::Layout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_columns = <Vec<crate::file::LayoutColumn>>::sse_decode(deserializer);
+        let mut var_fields = <Vec<String>>::sse_decode(deserializer);
+        return crate::file::Layout {
+            columns: var_columns,
+            fields: var_fields,
+        };
+    }
+}
+
+impl SseDecode for crate::file::LayoutCell {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_expr = <String>::sse_decode(deserializer);
+        let mut var_style = <crate::file::CellStyle>::sse_decode(deserializer);
+        let mut var_maxLines = <u32>::sse_decode(deserializer);
+        let mut var_ellipsis = <bool>::sse_decode(deserializer);
+        return crate::file::LayoutCell {
+            expr: var_expr,
+            style: var_style,
+            max_lines: var_maxLines,
+            ellipsis: var_ellipsis,
+        };
+    }
+}
+
+impl SseDecode for crate::file::LayoutColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <Option<f64>>::sse_decode(deserializer);
+        let mut var_flex = <Option<u32>>::sse_decode(deserializer);
+        let mut var_rows = <Vec<crate::file::LayoutCell>>::sse_decode(deserializer);
+        return crate::file::LayoutColumn {
+            width: var_width,
+            flex: var_flex,
+            rows: var_rows,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::FacetValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::FacetValue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::InferredField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::InferredField>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::LayoutCell> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::LayoutCell>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::LayoutColumn> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::LayoutColumn>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::Log> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::Log>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::file::MatchSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::file::MatchSpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseDecode for Vec<crate::file::SortKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+            ans_.push(<crate::file::SortKey>::sse_decode(deserializer));
         }
         return ans_;
     }
 }
 
-impl SseDecode for Vec<(String, String)> {
+impl SseDecode for Vec<crate::file::View> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
         for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
+            ans_.push(<crate::file::View>::sse_decode(deserializer));
         }
         return ans_;
     }
@@ -283,9 +951,13 @@ impl SseDecode for crate::file::Log {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_id = <u32>::sse_decode(deserializer);
         let mut var_fields = <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_matches = <Vec<crate::file::MatchSpan>>::sse_decode(deserializer);
+        let mut var_cells = <Vec<String>>::sse_decode(deserializer);
         return crate::file::Log {
             id: var_id,
             fields: var_fields,
+            matches: var_matches,
+            cells: var_cells,
         };
     }
 }
@@ -295,9 +967,25 @@ impl SseDecode for crate::file::Logs {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_logs = <Vec<crate::file::Log>>::sse_decode(deserializer);
         let mut var_totalCount = <u32>::sse_decode(deserializer);
+        let mut var_strategy = <Option<crate::file::SearchStrategy>>::sse_decode(deserializer);
         return crate::file::Logs {
             logs: var_logs,
             total_count: var_totalCount,
+            strategy: var_strategy,
+        };
+    }
+}
+
+impl SseDecode for crate::file::MatchSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::file::MatchSpan {
+            field: var_field,
+            start: var_start,
+            end: var_end,
         };
     }
 }
@@ -313,6 +1001,50 @@ impl SseDecode for Option<String> {
     }
 }
 
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::file::SearchStrategy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::file::SearchStrategy>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -322,6 +1054,73 @@ impl SseDecode for (String, String) {
     }
 }
 
+impl SseDecode for crate::file::SearchMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::SearchMode::Phrase,
+            1 => crate::file::SearchMode::Boolean,
+            2 => crate::file::SearchMode::Regex,
+            3 => crate::file::SearchMode::Literal,
+            _ => unreachable!("Invalid variant for SearchMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::file::SearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mode = <crate::file::SearchMode>::sse_decode(deserializer);
+        let mut var_caseSensitive = <bool>::sse_decode(deserializer);
+        let mut var_wholeWord = <bool>::sse_decode(deserializer);
+        return crate::file::SearchOptions {
+            mode: var_mode,
+            case_sensitive: var_caseSensitive,
+            whole_word: var_wholeWord,
+        };
+    }
+}
+
+impl SseDecode for crate::file::SearchStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::SearchStrategy::Trigram,
+            1 => crate::file::SearchStrategy::Scan,
+            _ => unreachable!("Invalid variant for SearchStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::file::SortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_column = <String>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_sortType = <crate::file::SortType>::sse_decode(deserializer);
+        return crate::file::SortKey {
+            column: var_column,
+            descending: var_descending,
+            sort_type: var_sortType,
+        };
+    }
+}
+
+impl SseDecode for crate::file::SortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::SortType::Text,
+            1 => crate::file::SortType::Integer,
+            2 => crate::file::SortType::Real,
+            _ => unreachable!("Invalid variant for SortType: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -341,17 +1140,40 @@ impl SseDecode for () {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
 }
 
-impl SseDecode for i32 {
+impl SseDecode for crate::file::ValueKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::file::ValueKind::Null,
+            1 => crate::file::ValueKind::Bool,
+            2 => crate::file::ValueKind::Integer,
+            3 => crate::file::ValueKind::Float,
+            4 => crate::file::ValueKind::String,
+            5 => crate::file::ValueKind::Array,
+            6 => crate::file::ValueKind::Mixed,
+            _ => unreachable!("Invalid variant for ValueKind: {}", inner),
+        };
     }
 }
 
-impl SseDecode for bool {
+impl SseDecode for crate::file::View {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_filterSql = <String>::sse_decode(deserializer);
+        let mut var_ftsQuery = <String>::sse_decode(deserializer);
+        let mut var_search = <crate::file::SearchOptions>::sse_decode(deserializer);
+        let mut var_sort = <Vec<crate::file::SortKey>>::sse_decode(deserializer);
+        let mut var_columns = <Vec<String>>::sse_decode(deserializer);
+        return crate::file::View {
+            name: var_name,
+            filter_sql: var_filterSql,
+            fts_query: var_ftsQuery,
+            search: var_search,
+            sort: var_sort,
+            columns: var_columns,
+        };
     }
 }
 
@@ -364,10 +1186,20 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        1 => wire__crate__file__get_file_status_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__file__get_log_detail_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__file__get_logs_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__file__open_file_impl(port, ptr, rust_vec_len, data_len),
+        1 => wire__crate__file__export_logs_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__file__export_raw_lines_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__file__get_context_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__file__get_export_progress_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__file__get_facets_impl(port, ptr, rust_vec_len, data_len),
+        6 => wire__crate__file__get_file_status_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__file__get_histogram_impl(port, ptr, rust_vec_len, data_len),
+        8 => wire__crate__file__get_layout_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__file__get_log_detail_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__file__get_log_raw_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__file__get_logs_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__file__infer_rules_impl(port, ptr, rust_vec_len, data_len),
+        13 => wire__crate__file__list_views_impl(port, ptr, rust_vec_len, data_len),
+        14 => wire__crate__file__open_file_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -383,60 +1215,367 @@ fn pde_ffi_dispatcher_sync_impl(
         _ => unreachable!(),
     }
 }
-
-// Section: rust2dart
-
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::CellStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Text => 0.into_dart(),
+            Self::Meta => 1.into_dart(),
+            Self::Tag => 2.into_dart(),
+            Self::ColorTag => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::CellStyle {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::CellStyle> for crate::file::CellStyle {
+    fn into_into_dart(self) -> crate::file::CellStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::ExportFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Csv => 0.into_dart(),
+            Self::Jsonl => 1.into_dart(),
+            Self::JsonlRaw => 2.into_dart(),
+            Self::Parquet => 3.into_dart(),
+            Self::Cells => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::ExportFormat {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::ExportFormat> for crate::file::ExportFormat {
+    fn into_into_dart(self) -> crate::file::ExportFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::FacetValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.value.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::FacetValue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::FacetValue> for crate::file::FacetValue {
+    fn into_into_dart(self) -> crate::file::FacetValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::FileStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::file::FileStatus::Uninit => [0.into_dart()].into_dart(),
+            crate::file::FileStatus::Pending => [1.into_dart()].into_dart(),
+            crate::file::FileStatus::Complete => [2.into_dart()].into_dart(),
+            crate::file::FileStatus::Error(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::FileStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::FileStatus> for crate::file::FileStatus {
+    fn into_into_dart(self) -> crate::file::FileStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::Histogram {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.bucket_ms.into_into_dart().into_dart(),
+            self.counts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Histogram {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::Histogram> for crate::file::Histogram {
+    fn into_into_dart(self) -> crate::file::Histogram {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::InferredField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.fill_rate.into_into_dart().into_dart(),
+            self.repeated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::InferredField {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::InferredField> for crate::file::InferredField {
+    fn into_into_dart(self) -> crate::file::InferredField {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::InferredRules {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rules.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.time_field.into_into_dart().into_dart(),
+            self.sampled_lines.into_into_dart().into_dart(),
+            self.json_lines.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::InferredRules {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::InferredRules> for crate::file::InferredRules {
+    fn into_into_dart(self) -> crate::file::InferredRules {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::Layout {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.columns.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Layout {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::Layout> for crate::file::Layout {
+    fn into_into_dart(self) -> crate::file::Layout {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::LayoutCell {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.expr.into_into_dart().into_dart(),
+            self.style.into_into_dart().into_dart(),
+            self.max_lines.into_into_dart().into_dart(),
+            self.ellipsis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::LayoutCell {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::LayoutCell> for crate::file::LayoutCell {
+    fn into_into_dart(self) -> crate::file::LayoutCell {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::LayoutColumn {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.flex.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::LayoutColumn {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::LayoutColumn> for crate::file::LayoutColumn {
+    fn into_into_dart(self) -> crate::file::LayoutColumn {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::Log {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.matches.into_into_dart().into_dart(),
+            self.cells.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Log {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::Log> for crate::file::Log {
+    fn into_into_dart(self) -> crate::file::Log {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::Logs {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.logs.into_into_dart().into_dart(),
+            self.total_count.into_into_dart().into_dart(),
+            self.strategy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Logs {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::Logs> for crate::file::Logs {
+    fn into_into_dart(self) -> crate::file::Logs {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::MatchSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::MatchSpan {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::MatchSpan> for crate::file::MatchSpan {
+    fn into_into_dart(self) -> crate::file::MatchSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::SearchMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Phrase => 0.into_dart(),
+            Self::Boolean => 1.into_dart(),
+            Self::Regex => 2.into_dart(),
+            Self::Literal => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::SearchMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::SearchMode> for crate::file::SearchMode {
+    fn into_into_dart(self) -> crate::file::SearchMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::SearchOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mode.into_into_dart().into_dart(),
+            self.case_sensitive.into_into_dart().into_dart(),
+            self.whole_word.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::SearchOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::SearchOptions> for crate::file::SearchOptions {
+    fn into_into_dart(self) -> crate::file::SearchOptions {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::file::FileStatus {
+impl flutter_rust_bridge::IntoDart for crate::file::SearchStrategy {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            crate::file::FileStatus::Uninit => [0.into_dart()].into_dart(),
-            crate::file::FileStatus::Pending => [1.into_dart()].into_dart(),
-            crate::file::FileStatus::Complete => [2.into_dart()].into_dart(),
-            crate::file::FileStatus::Error(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+            Self::Trigram => 0.into_dart(),
+            Self::Scan => 1.into_dart(),
+            _ => unreachable!(),
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::FileStatus {}
-impl flutter_rust_bridge::IntoIntoDart<crate::file::FileStatus> for crate::file::FileStatus {
-    fn into_into_dart(self) -> crate::file::FileStatus {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::SearchStrategy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::SearchStrategy>
+    for crate::file::SearchStrategy
+{
+    fn into_into_dart(self) -> crate::file::SearchStrategy {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::file::Log {
+impl flutter_rust_bridge::IntoDart for crate::file::SortKey {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.id.into_into_dart().into_dart(),
-            self.fields.into_into_dart().into_dart(),
+            self.column.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.sort_type.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Log {}
-impl flutter_rust_bridge::IntoIntoDart<crate::file::Log> for crate::file::Log {
-    fn into_into_dart(self) -> crate::file::Log {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::SortKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::SortKey> for crate::file::SortKey {
+    fn into_into_dart(self) -> crate::file::SortKey {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::file::Logs {
+impl flutter_rust_bridge::IntoDart for crate::file::SortType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Text => 0.into_dart(),
+            Self::Integer => 1.into_dart(),
+            Self::Real => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::SortType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::SortType> for crate::file::SortType {
+    fn into_into_dart(self) -> crate::file::SortType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::ValueKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Null => 0.into_dart(),
+            Self::Bool => 1.into_dart(),
+            Self::Integer => 2.into_dart(),
+            Self::Float => 3.into_dart(),
+            Self::String => 4.into_dart(),
+            Self::Array => 5.into_dart(),
+            Self::Mixed => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::ValueKind {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::ValueKind> for crate::file::ValueKind {
+    fn into_into_dart(self) -> crate::file::ValueKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::file::View {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.logs.into_into_dart().into_dart(),
-            self.total_count.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.filter_sql.into_into_dart().into_dart(),
+            self.fts_query.into_into_dart().into_dart(),
+            self.search.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.columns.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::Logs {}
-impl flutter_rust_bridge::IntoIntoDart<crate::file::Logs> for crate::file::Logs {
-    fn into_into_dart(self) -> crate::file::Logs {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::file::View {}
+impl flutter_rust_bridge::IntoIntoDart<crate::file::View> for crate::file::View {
+    fn into_into_dart(self) -> crate::file::View {
         self
     }
 }
@@ -462,6 +1601,65 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::file::CellStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::file::CellStyle::Text => 0,
+                crate::file::CellStyle::Meta => 1,
+                crate::file::CellStyle::Tag => 2,
+                crate::file::CellStyle::ColorTag => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::file::ExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::file::ExportFormat::Csv => 0,
+                crate::file::ExportFormat::Jsonl => 1,
+                crate::file::ExportFormat::JsonlRaw => 2,
+                crate::file::ExportFormat::Parquet => 3,
+                crate::file::ExportFormat::Cells => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::file::FacetValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.value, serializer);
+        <u32>::sse_encode(self.count, serializer);
+    }
+}
+
 impl SseEncode for crate::file::FileStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -486,6 +1684,128 @@ impl SseEncode for crate::file::FileStatus {
     }
 }
 
+impl SseEncode for crate::file::Histogram {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.start_ms, serializer);
+        <i64>::sse_encode(self.bucket_ms, serializer);
+        <Vec<u32>>::sse_encode(self.counts, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::file::InferredField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <crate::file::ValueKind>::sse_encode(self.kind, serializer);
+        <f64>::sse_encode(self.fill_rate, serializer);
+        <bool>::sse_encode(self.repeated, serializer);
+    }
+}
+
+impl SseEncode for crate::file::InferredRules {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.rules, serializer);
+        <Vec<crate::file::InferredField>>::sse_encode(self.fields, serializer);
+        <Option<String>>::sse_encode(self.time_field, serializer);
+        <u32>::sse_encode(self.sampled_lines, serializer);
+        <u32>::sse_encode(self.json_lines, serializer);
+    }
+}
+
+impl SseEncode for crate::file::Layout {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::file::LayoutColumn>>::sse_encode(self.columns, serializer);
+        <Vec<String>>::sse_encode(self.fields, serializer);
+    }
+}
+
+impl SseEncode for crate::file::LayoutCell {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.expr, serializer);
+        <crate::file::CellStyle>::sse_encode(self.style, serializer);
+        <u32>::sse_encode(self.max_lines, serializer);
+        <bool>::sse_encode(self.ellipsis, serializer);
+    }
+}
+
+impl SseEncode for crate::file::LayoutColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.width, serializer);
+        <Option<u32>>::sse_encode(self.flex, serializer);
+        <Vec<crate::file::LayoutCell>>::sse_encode(self.rows, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::file::FacetValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::FacetValue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::file::InferredField> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::InferredField>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::file::LayoutCell> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::LayoutCell>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::file::LayoutColumn> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::LayoutColumn>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::file::Log> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -496,6 +1816,26 @@ impl SseEncode for Vec<crate::file::Log> {
     }
 }
 
+impl SseEncode for Vec<crate::file::MatchSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::MatchSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -516,11 +1856,33 @@ impl SseEncode for Vec<(String, String)> {
     }
 }
 
+impl SseEncode for Vec<crate::file::SortKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::SortKey>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::file::View> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::file::View>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::file::Log {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u32>::sse_encode(self.id, serializer);
         <std::collections::HashMap<String, String>>::sse_encode(self.fields, serializer);
+        <Vec<crate::file::MatchSpan>>::sse_encode(self.matches, serializer);
+        <Vec<String>>::sse_encode(self.cells, serializer);
     }
 }
 
@@ -529,6 +1891,16 @@ impl SseEncode for crate::file::Logs {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Vec<crate::file::Log>>::sse_encode(self.logs, serializer);
         <u32>::sse_encode(self.total_count, serializer);
+        <Option<crate::file::SearchStrategy>>::sse_encode(self.strategy, serializer);
+    }
+}
+
+impl SseEncode for crate::file::MatchSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
     }
 }
 
@@ -542,6 +1914,46 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::file::SearchStrategy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::file::SearchStrategy>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -550,6 +1962,75 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for crate::file::SearchMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::file::SearchMode::Phrase => 0,
+                crate::file::SearchMode::Boolean => 1,
+                crate::file::SearchMode::Regex => 2,
+                crate::file::SearchMode::Literal => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::file::SearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::file::SearchMode>::sse_encode(self.mode, serializer);
+        <bool>::sse_encode(self.case_sensitive, serializer);
+        <bool>::sse_encode(self.whole_word, serializer);
+    }
+}
+
+impl SseEncode for crate::file::SearchStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::file::SearchStrategy::Trigram => 0,
+                crate::file::SearchStrategy::Scan => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::file::SortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.column, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <crate::file::SortType>::sse_encode(self.sort_type, serializer);
+    }
+}
+
+impl SseEncode for crate::file::SortType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::file::SortType::Text => 0,
+                crate::file::SortType::Integer => 1,
+                crate::file::SortType::Real => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -569,17 +2050,36 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::file::ValueKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::file::ValueKind::Null => 0,
+                crate::file::ValueKind::Bool => 1,
+                crate::file::ValueKind::Integer => 2,
+                crate::file::ValueKind::Float => 3,
+                crate::file::ValueKind::String => 4,
+                crate::file::ValueKind::Array => 5,
+                crate::file::ValueKind::Mixed => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::file::View {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.filter_sql, serializer);
+        <String>::sse_encode(self.fts_query, serializer);
+        <crate::file::SearchOptions>::sse_encode(self.search, serializer);
+        <Vec<crate::file::SortKey>>::sse_encode(self.sort, serializer);
+        <Vec<String>>::sse_encode(self.columns, serializer);
     }
 }
 
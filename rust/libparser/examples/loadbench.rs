@@ -0,0 +1,98 @@
+use anyhow::Result;
+use argh::FromArgs;
+use libparser::{Engine, LoadOptions, RawStorage, ReaderBackend};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+const EVENT_NAMES: [&str; 3] = ["Error", "Warn", "Info"];
+
+#[derive(FromArgs)]
+#[argh(description = "Compare load_file reader backends on a generated corpus")]
+struct Args {
+    #[argh(
+        option,
+        default = "String::from(\"lkl2-bench.log\")",
+        description = "corpus path, generated if missing"
+    )]
+    file: String,
+    #[argh(option, default = "100", description = "corpus size in MB")]
+    size_mb: u64,
+    #[argh(option, default = "3", description = "loads per backend")]
+    runs: u32,
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+    let args: Args = argh::from_env();
+    if !Path::new(&args.file).exists() {
+        generate(&args.file, args.size_mb << 20)?;
+    }
+
+    println!("backend\tstorage\tlines\ttotal\tread\tjson\tfull json\tsqlite\tfts");
+    for reader in [ReaderBackend::Buffered, ReaderBackend::Mmap] {
+        for storage in [RawStorage::Inline, RawStorage::Offsets] {
+            let options = LoadOptions {
+                reader,
+                storage,
+                ..Default::default()
+            };
+            for _ in 0..args.runs {
+                let mut engine = Engine::from_embedded_config()?;
+                let stats = engine.load_file_with(&args.file, &options)?;
+                println!(
+                    "{:?}\t{:?}\t{}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}\t{:?}",
+                    reader,
+                    storage,
+                    stats.inserted_lines,
+                    stats.total_duration,
+                    stats.read_duration,
+                    stats.parse_duration,
+                    stats.full_parse_estimate,
+                    stats.db_duration,
+                    stats.fts_duration
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes JSON lines shaped like the app's telemetry logs, each with a
+/// 1-2 KB `extra_data` blob that no rule references, until `size` bytes.
+fn generate(path: &str, size: u64) -> Result<()> {
+    println!("Generating {} MB corpus at {}", size >> 20, path);
+    let mut out = BufWriter::new(File::create(path)?);
+    // xorshift keeps the corpus identical between runs without a rand dependency.
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+    let mut written = 0u64;
+    let mut i = 0u64;
+    while written < size {
+        let extra_len = 1024 + (next() % 1024) as usize;
+        let extra: String = (0..extra_len)
+            .map(|_| char::from(b'a' + (next() % 26) as u8))
+            .collect();
+        let line = format!(
+            "{{\"Event\": {{\"paltformUtcTime\": \"2026-01-01T{:02}:{:02}:{:02}.{:03}Z\", \"telemetryEventName\": \"{}\", \"SourcenodeId\": {}, \"TargetnodeId\": {}}}, \"extra_data\": \"{}\"}}\n",
+            i / 3_600_000 % 24,
+            i / 60_000 % 60,
+            i / 1000 % 60,
+            i % 1000,
+            EVENT_NAMES[(next() % 3) as usize],
+            next() % 16,
+            next() % 16,
+            extra
+        );
+        out.write_all(line.as_bytes())?;
+        written += line.len() as u64;
+        i += 1;
+    }
+    out.flush()?;
+    Ok(())
+}
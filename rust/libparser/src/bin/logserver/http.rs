@@ -0,0 +1,288 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::io::{self, BufRead, Read, Write};
+
+/// Largest request body accepted; queries are small.
+const MAX_BODY: usize = 1 << 20;
+/// Largest request line or header accepted.
+const MAX_LINE: usize = 8 << 10;
+/// Most headers accepted in one request.
+const MAX_HEADERS: usize = 100;
+
+/// An HTTP/1.1 request. Only what the API needs is kept: the query string and
+/// headers other than `Content-Length`, `Host` and `Origin` are dropped.
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub host: Option<String>,
+    pub origin: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Whether the request names localhost in `Host` and, if it comes from
+    /// a web page, the page is on localhost too. Browsers send the host name
+    /// they resolved, which stops DNS rebinding, and the `Origin` of the page
+    /// making the request, which stops other sites from posting queries.
+    pub fn is_local(&self) -> bool {
+        self.host.as_deref().is_some_and(is_local_host)
+            && self
+                .origin
+                .as_deref()
+                .is_none_or(|origin| origin.strip_prefix("http://").is_some_and(is_local_host))
+    }
+}
+
+/// `localhost` or `127.0.0.1`, with or without a port.
+fn is_local_host(authority: &str) -> bool {
+    let host = match authority.split_once(':') {
+        Some((host, port)) if port.bytes().all(|b| b.is_ascii_digit()) => host,
+        Some(_) => return false,
+        None => authority,
+    };
+    host.eq_ignore_ascii_case("localhost") || host == "127.0.0.1"
+}
+
+/// A JSON response. Every response closes the connection.
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn json(status: u16, value: &impl Serialize) -> Self {
+        Response {
+            status,
+            body: serde_json::to_vec(value).expect("API values serialize"),
+        }
+    }
+
+    /// `{"error": message}`.
+    pub fn error(status: u16, message: impl std::fmt::Display) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message.to_string() }))
+    }
+
+    pub fn write_to(&self, out: &mut impl Write) -> Result<()> {
+        write!(
+            out,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            self.status,
+            reason(self.status),
+            self.body.len()
+        )?;
+        out.write_all(&self.body)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Reads one request, or `None` when the client closed the connection
+/// without sending one. Malformed requests are answered with the returned
+/// error response.
+pub fn read_request(reader: &mut impl BufRead) -> Result<Option<Request>, Response> {
+    let Some(request_line) = read_line(reader)? else {
+        return Ok(None);
+    };
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, "Malformed request line"));
+    };
+    let path = target.split_once('?').map_or(target, |(path, _)| path);
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    let (mut host, mut origin) = (None, None);
+    for count in 0.. {
+        if count > MAX_HEADERS {
+            return Err(Response::error(431, "Too many headers"));
+        }
+        let Some(header) = read_line(reader)? else {
+            return Err(Response::error(400, "Truncated headers"));
+        };
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(Response::error(400, "Malformed header"));
+        };
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("host") {
+            host = Some(value.trim().to_string());
+        } else if name.eq_ignore_ascii_case("origin") {
+            origin = Some(value.trim().to_string());
+        }
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(411, "Chunked bodies are not supported"));
+        }
+        if name.eq_ignore_ascii_case("content-length") {
+            length = value
+                .trim()
+                .parse()
+                .map_err(|_| Response::error(400, "Invalid Content-Length"))?;
+        }
+    }
+    if length > MAX_BODY {
+        return Err(Response::error(413, "Request body too large"));
+    }
+
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| read_error(e, "Truncated body"))?;
+    Ok(Some(Request {
+        method,
+        path,
+        host,
+        origin,
+        body,
+    }))
+}
+
+/// One CRLF-terminated line without its line ending.
+fn read_line(reader: &mut impl BufRead) -> Result<Option<String>, Response> {
+    let mut line = Vec::new();
+    let read = reader
+        .by_ref()
+        .take(MAX_LINE as u64 + 1)
+        .read_until(b'\n', &mut line)
+        .map_err(|e| read_error(e, "Failed to read request"))?;
+    if read == 0 {
+        return Ok(None);
+    }
+    if line.len() > MAX_LINE {
+        return Err(Response::error(431, "Request line or header too long"));
+    }
+    let line = String::from_utf8(line)
+        .context("Request is not valid UTF-8")
+        .map_err(|e| Response::error(400, e))?;
+    Ok(Some(line.trim_end_matches(['\r', '\n']).to_string()))
+}
+
+/// 408 when the client was too slow, see `TcpStream::set_read_timeout`,
+/// otherwise 400 with `message`.
+fn read_error(error: io::Error, message: &str) -> Response {
+    match error.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+            Response::error(408, "Timed out reading the request")
+        }
+        _ => Response::error(400, message),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The request parsed from `text`, or the status it is answered with.
+    fn parse(text: impl AsRef<[u8]>) -> Result<Option<Request>, u16> {
+        read_request(&mut text.as_ref()).map_err(|response| response.status)
+    }
+
+    fn request(host: Option<&str>, origin: Option<&str>) -> Request {
+        Request {
+            method: "GET".to_string(),
+            path: "/".to_string(),
+            host: host.map(String::from),
+            origin: origin.map(String::from),
+            body: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parses_a_request() {
+        let text = "POST /logs?x=1 HTTP/1.1\r\nHost: localhost:8080\r\n\
+                    origin: http://localhost:3000\r\nContent-Length: 2\r\n\r\n{}";
+        let request = parse(text).unwrap().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/logs");
+        assert_eq!(request.host.as_deref(), Some("localhost:8080"));
+        assert_eq!(request.origin.as_deref(), Some("http://localhost:3000"));
+        assert_eq!(request.body, b"{}");
+        assert!(parse("").unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_malformed_requests() {
+        let cases = [
+            ("GET\r\n\r\n", 400),
+            ("\r\n\r\n", 400),
+            ("GET / HTTP/1.1\r\nHost: localhost\r\n", 400),
+            ("GET / HTTP/1.1\r\nHost localhost\r\n\r\n", 400),
+            ("GET / HTTP/1.1\r\nContent-Length: -1\r\n\r\n", 400),
+            ("GET / HTTP/1.1\r\nContent-Length: 5\r\n\r\n{}", 400),
+            ("GET / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n", 411),
+            ("GET / HTTP/1.1\r\nContent-Length: 1048577\r\n\r\n", 413),
+        ];
+        for (text, status) in cases {
+            assert_eq!(parse(text).err(), Some(status), "{:?}", text);
+        }
+        assert_eq!(parse(b"GET /\xff HTTP/1.1\r\n\r\n").err(), Some(400));
+    }
+
+    #[test]
+    fn rejects_oversized_headers() {
+        let long_path = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE));
+        assert_eq!(parse(long_path).err(), Some(431));
+        let long_header = format!("GET / HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(MAX_LINE));
+        assert_eq!(parse(long_header).err(), Some(431));
+        let many = format!(
+            "GET / HTTP/1.1\r\n{}\r\n",
+            "X: 1\r\n".repeat(MAX_HEADERS + 1)
+        );
+        assert_eq!(parse(many).err(), Some(431));
+        let enough = format!("GET / HTTP/1.1\r\n{}\r\n", "X: 1\r\n".repeat(MAX_HEADERS));
+        assert!(parse(enough).is_ok());
+    }
+
+    #[test]
+    fn local_hosts_and_origins() {
+        for host in ["localhost", "LocalHost:8080", "127.0.0.1", "127.0.0.1:80"] {
+            assert!(request(Some(host), None).is_local(), "{}", host);
+        }
+        let origin = Some("http://localhost:3000");
+        assert!(request(Some("localhost:8080"), origin).is_local());
+    }
+
+    #[test]
+    fn non_local_hosts_and_origins() {
+        for host in [
+            "example.com",
+            "localhost.example.com",
+            "127.0.0.2",
+            "localhost:80@example.com",
+            "[::1]:8080",
+            "",
+        ] {
+            assert!(!request(Some(host), None).is_local(), "{}", host);
+        }
+        assert!(!request(None, None).is_local());
+        for origin in [
+            "http://example.com",
+            "https://localhost",
+            "http://localhost.example.com",
+            "null",
+            "",
+        ] {
+            assert!(
+                !request(Some("localhost"), Some(origin)).is_local(),
+                "{}",
+                origin
+            );
+        }
+    }
+}
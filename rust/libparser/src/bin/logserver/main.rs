@@ -0,0 +1,269 @@
+mod http;
+
+use anyhow::Result;
+use argh::FromArgs;
+use http::{Request, Response};
+use libparser::{Config, DecodeMode, Engine, LoadOptions, LogQuery};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::json;
+use std::io::BufReader;
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Page size of `/logs` when the request gives no `limit`.
+const DEFAULT_LIMIT: u32 = 100;
+/// Values returned by `/facets` when the request gives no `limit`.
+const DEFAULT_FACET_LIMIT: u32 = 20;
+/// How long a client may take to send each part of its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Connections served at once. Requests take turns on the engine anyway;
+/// more workers only keep slow clients from holding up the others. Further
+/// connections wait in the queue, then in the listen backlog.
+const WORKERS: usize = 8;
+
+#[derive(FromArgs)]
+/// Serve a JSON log file over HTTP on localhost, answering the same queries
+/// as the app. SQL can only read, and only requests to localhost from
+/// localhost pages are answered.
+#[argh(
+    note = "Endpoints, all answering JSON:
+  GET  /         the columns of the logs table
+  POST /logs     a page of logs; body: a LogQuery
+  GET  /logs/ID  the raw line of one log
+  POST /facets   value counts of a column; body: field, limit and a LogQuery
+  POST /sql      the result of any SQL statement; body: {{\"sql\": \"...\"}}",
+    error_code(2, "The rules or the log file could not be loaded.")
+)]
+struct Args {
+    #[argh(positional, description = "path to the log file")]
+    file: String,
+    #[argh(option, description = "rule file to use instead of the built-in one")]
+    rules: Option<String>,
+    #[argh(
+        option,
+        short = 'p',
+        default = "8080",
+        description = "port to listen on at 127.0.0.1"
+    )]
+    port: u16,
+}
+
+/// Body of `/facets`: `field` and `limit` as for `get_facets`, plus the
+/// filter fields of a `LogQuery`.
+#[derive(Deserialize)]
+struct FacetRequest {
+    field: String,
+    #[serde(default = "default_facet_limit")]
+    limit: u32,
+    #[serde(flatten)]
+    query: LogQuery,
+}
+
+#[derive(Deserialize)]
+struct SqlRequest {
+    sql: String,
+}
+
+fn default_facet_limit() -> u32 {
+    DEFAULT_FACET_LIMIT
+}
+
+fn main() -> ExitCode {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let args: Args = argh::from_env();
+
+    let engine = match open(args.rules.as_deref(), &args.file) {
+        Ok(engine) => engine,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            return ExitCode::from(2);
+        }
+    };
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, args.port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Error: Failed to listen on port {}: {}", args.port, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    log::info!("Serving {} on http://127.0.0.1:{}", args.file, args.port);
+
+    let engine = Arc::new(Mutex::new(engine));
+    let (sender, receiver) = mpsc::sync_channel(WORKERS);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..WORKERS {
+        let (engine, receiver) = (engine.clone(), receiver.clone());
+        thread::spawn(move || work(&receiver, &engine));
+    }
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if sender.send(stream).is_err() {
+                    eprintln!("Error: Every worker thread has stopped");
+                    return ExitCode::FAILURE;
+                }
+            }
+            Err(e) => log::warn!("Failed to accept connection: {}", e),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Serves the connections of `receiver` one after the other. A request that
+/// panics only loses its own connection.
+fn work(receiver: &Mutex<Receiver<TcpStream>>, engine: &Mutex<Engine>) {
+    loop {
+        let stream = receiver
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .recv();
+        let Ok(stream) = stream else {
+            return;
+        };
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| serve(stream, engine)));
+    }
+}
+
+/// Loads `file` the way the app does, so `raw` and searches behave the same.
+fn open(rules: Option<&str>, file: &str) -> Result<Engine> {
+    let mut engine = match rules {
+        Some(path) => Engine::new(Config::from_file(path)?)?,
+        None => Engine::from_embedded_config()?,
+    };
+    let options = LoadOptions {
+        decode: DecodeMode::Raw,
+        ..Default::default()
+    };
+    let stats = engine.load_file_with(file, &options)?;
+    engine.restrict_to_reads();
+    log::info!(
+        "Loaded {} lines ({} not valid UTF-8) in {:?}. Columns: {:?}",
+        stats.inserted_lines,
+        stats.malformed_lines,
+        stats.total_duration,
+        engine.columns()
+    );
+    Ok(engine)
+}
+
+/// Answers the one request of a connection.
+fn serve(stream: TcpStream, engine: &Mutex<Engine>) {
+    let start = Instant::now();
+    if let Err(e) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+        log::warn!("Failed to set a read timeout: {}", e);
+        return;
+    }
+    let mut reader = BufReader::new(&stream);
+    let (request, response) = match http::read_request(&mut reader) {
+        Ok(Some(request)) if !request.is_local() => {
+            let response = Response::error(403, "Only requests to localhost are served");
+            (Some(request), response)
+        }
+        Ok(Some(request)) => {
+            // Requests only read, so one that panicked leaves the engine
+            // usable.
+            let engine = engine.lock().unwrap_or_else(PoisonError::into_inner);
+            let response = route(&engine, &request);
+            (Some(request), response)
+        }
+        Ok(None) => return,
+        Err(response) => (None, response),
+    };
+    let (method, path) = request
+        .as_ref()
+        .map_or(("-", "-"), |r| (r.method.as_str(), r.path.as_str()));
+    log::info!(
+        "{} {} {} ({:?})",
+        method,
+        path,
+        response.status,
+        start.elapsed()
+    );
+    if let Err(e) = response.write_to(&mut &stream) {
+        log::warn!("Failed to send response: {}", e);
+    }
+}
+
+fn route(engine: &Engine, request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+    let allowed = match segments.as_slice() {
+        [] => "GET",
+        ["logs", _] => "GET",
+        ["logs"] | ["facets"] | ["sql"] => "POST",
+        _ => return Response::error(404, format!("No endpoint at {}", request.path)),
+    };
+    if request.method != allowed {
+        return Response::error(405, format!("{} only accepts {}", request.path, allowed));
+    }
+    let res = match segments.as_slice() {
+        [] => Ok(Response::json(200, &json!({ "columns": engine.columns() }))),
+        ["logs", id] => match id.parse() {
+            Ok(id) => get_log(engine, id),
+            Err(_) => Ok(Response::error(404, format!("Invalid log id '{}'", id))),
+        },
+        ["logs"] => get_logs(engine, &request.body),
+        ["facets"] => get_facets(engine, &request.body),
+        _ => execute_sql(engine, &request.body),
+    };
+    res.unwrap_or_else(|e| Response::error(400, format!("{:#}", e)))
+}
+
+fn get_logs(engine: &Engine, body: &[u8]) -> Result<Response> {
+    let mut query: LogQuery = parse_body(body)?;
+    if query.limit == 0 {
+        query.limit = DEFAULT_LIMIT;
+    }
+    let page = engine.get_logs(&query)?;
+    let total_count = page.total_count;
+    let strategy = page.strategy;
+    Ok(Response::json(
+        200,
+        &json!({
+            "logs": page.into_records(),
+            "total_count": total_count,
+            "strategy": strategy,
+        }),
+    ))
+}
+
+/// Like the app's detail view, bytes that are not valid UTF-8 show as U+FFFD.
+fn get_log(engine: &Engine, id: i64) -> Result<Response> {
+    Ok(match engine.get_raw(id)? {
+        Some(raw) => Response::json(
+            200,
+            &json!({ "id": id, "raw": String::from_utf8_lossy(&raw) }),
+        ),
+        None => Response::error(404, format!("No log with id {}", id)),
+    })
+}
+
+fn get_facets(engine: &Engine, body: &[u8]) -> Result<Response> {
+    let request: FacetRequest = parse_body(body)?;
+    let facets = engine.get_facets(&request.field, &request.query, request.limit)?;
+    Ok(Response::json(200, &facets))
+}
+
+fn execute_sql(engine: &Engine, body: &[u8]) -> Result<Response> {
+    let request: SqlRequest = parse_body(body)?;
+    let result = engine.execute_query(&request.sql)?;
+    Ok(Response::json(
+        200,
+        &json!({ "headers": result.headers, "rows": result.rows }),
+    ))
+}
+
+/// An empty body stands for `{}`.
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    let body = if body.iter().all(u8::is_ascii_whitespace) {
+        &b"{}"[..]
+    } else {
+        body
+    };
+    Ok(serde_json::from_slice(body)?)
+}
@@ -0,0 +1,250 @@
+use crate::output::OutputMode;
+use crate::{should_exit, COMMANDS};
+use libparser::Engine;
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
+use rustyline::highlight::{CmdKind, Highlighter};
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Helper};
+use std::borrow::Cow;
+
+/// Completed and highlighted case-insensitively.
+const KEYWORDS: &[&str] = &[
+    "ALL", "AND", "AS", "ASC", "AVG", "BETWEEN", "BY", "CASE", "CAST", "COUNT", "DESC", "DISTINCT",
+    "ELSE", "END", "ESCAPE", "EXISTS", "EXPLAIN", "FROM", "GLOB", "GROUP", "HAVING", "IN", "INNER",
+    "INTEGER", "IS", "JOIN", "LEFT", "LIKE", "LIMIT", "MATCH", "MAX", "MIN", "NOT", "NULL",
+    "OFFSET", "ON", "OR", "ORDER", "PLAN", "QUERY", "REAL", "REGEXP", "SELECT", "SUM", "TEXT",
+    "THEN", "UNION", "WHEN", "WHERE", "WITH",
+];
+const TABLES: &[&str] = &["logs", "logs_fts"];
+
+const KEYWORD_STYLE: &str = "\x1b[1;34m";
+const STRING_STYLE: &str = "\x1b[32m";
+const NUMBER_STYLE: &str = "\x1b[33m";
+const COMMENT_STYLE: &str = "\x1b[90m";
+const COMMAND_STYLE: &str = "\x1b[35m";
+const RESET: &str = "\x1b[0m";
+
+/// Completion, highlighting and multi-line input for the REPL. Columns and
+/// views come from the engine and are refreshed with [`ShellHelper::refresh`]
+/// after every statement, since `.rules` can change them.
+pub struct ShellHelper {
+    columns: Vec<String>,
+    views: Vec<String>,
+    files: FilenameCompleter,
+}
+
+impl ShellHelper {
+    pub fn new(engine: &Engine) -> Self {
+        let mut helper = ShellHelper {
+            columns: Vec::new(),
+            views: Vec::new(),
+            files: FilenameCompleter::new(),
+        };
+        helper.refresh(engine);
+        helper
+    }
+
+    pub fn refresh(&mut self, engine: &Engine) {
+        self.columns = engine.columns();
+        self.views = engine
+            .config()
+            .views
+            .iter()
+            .map(|v| v.name.clone())
+            .collect();
+    }
+
+    /// Candidates for the argument of dot-command `command`, or `None` when
+    /// the argument is SQL.
+    fn complete_argument(&self, command: &str, word: &str) -> Option<Vec<Pair>> {
+        let options: Vec<String> = match command {
+            ".view" => self.views.clone(),
+            ".mode" => OutputMode::NAMES.split('|').map(String::from).collect(),
+            ".pager" | ".timer" | ".cells" => vec!["on".into(), "off".into()],
+            ".explain" => return None,
+            _ => Vec::new(),
+        };
+        Some(
+            options
+                .into_iter()
+                .filter(|o| o.starts_with(word))
+                .map(|o| pair(o.clone(), o))
+                .collect(),
+        )
+    }
+
+    fn complete_sql(&self, word: &str) -> Vec<Pair> {
+        if word.is_empty() {
+            return Vec::new();
+        }
+        let lower = word.to_lowercase();
+        // Keywords follow the case being typed.
+        let keyword_case = |k: &str| {
+            if word.chars().all(|c| !c.is_uppercase()) {
+                k.to_lowercase()
+            } else {
+                k.to_string()
+            }
+        };
+        let names = TABLES
+            .iter()
+            .map(|t| t.to_string())
+            .chain(self.columns.iter().cloned());
+        KEYWORDS
+            .iter()
+            .map(|k| keyword_case(k))
+            .chain(names)
+            .filter(|c| c.to_lowercase().starts_with(&lower))
+            .map(|c| pair(c.clone(), c))
+            .collect()
+    }
+}
+
+impl Helper for ShellHelper {}
+
+impl Hinter for ShellHelper {
+    type Hint = String;
+}
+
+impl Completer for ShellHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let before = &line[..pos];
+        let start = before
+            .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .map_or(0, |i| i + 1);
+        let word = &before[start..];
+
+        let trimmed = before.trim_start();
+        if trimmed.starts_with('.') {
+            let Some((command, _)) = trimmed.split_once(char::is_whitespace) else {
+                let commands = COMMANDS
+                    .iter()
+                    .map(|(usage, _)| usage.split(' ').next().unwrap_or_default())
+                    .filter(|c| c.starts_with(trimmed))
+                    .map(|c| pair(c.to_string(), format!("{} ", c)))
+                    .collect();
+                return Ok((pos - trimmed.len(), commands));
+            };
+            if matches!(command, ".load" | ".rules" | ".export") {
+                return self.files.complete(line, pos, ctx);
+            }
+            if let Some(candidates) = self.complete_argument(command, word) {
+                return Ok((start, candidates));
+            }
+        }
+        Ok((start, self.complete_sql(word)))
+    }
+}
+
+impl Highlighter for ShellHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let indent = line.len() - line.trim_start().len();
+        if line[indent..].starts_with('.') {
+            let end = line[indent..]
+                .find(char::is_whitespace)
+                .map_or(line.len(), |i| indent + i);
+            let (command, rest) = line.split_at(end);
+            return Cow::Owned(format!("{}{}{}{}", COMMAND_STYLE, command, RESET, rest));
+        }
+        Cow::Owned(highlight_sql(line))
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize, kind: CmdKind) -> bool {
+        kind != CmdKind::MoveCursor
+    }
+}
+
+impl Validator for ShellHelper {
+    /// SQL continues on the next line until it ends with `;`; dot-commands
+    /// and `exit` are single lines.
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let input = ctx.input();
+        let trimmed = input.trim();
+        if trimmed.is_empty()
+            || trimmed.starts_with('.')
+            || should_exit("", trimmed)
+            || ends_statement(input)
+        {
+            Ok(ValidationResult::Valid(None))
+        } else {
+            Ok(ValidationResult::Incomplete)
+        }
+    }
+}
+
+fn pair(display: String, replacement: String) -> Pair {
+    Pair {
+        display,
+        replacement,
+    }
+}
+
+/// Whether the last character of `sql` outside strings and comments is `;`.
+fn ends_statement(sql: &str) -> bool {
+    let mut last = None;
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                // An unterminated string keeps the statement open.
+                if !chars.by_ref().any(|q| q == c) {
+                    return false;
+                }
+                last = Some(c);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                chars.by_ref().take_while(|&n| n != '\n').for_each(drop);
+            }
+            c if c.is_whitespace() => {}
+            c => last = Some(c),
+        }
+    }
+    last == Some(';')
+}
+
+fn highlight_sql(sql: &str) -> String {
+    let mut out = String::with_capacity(sql.len() * 2);
+    let mut rest = sql;
+    while let Some(c) = rest.chars().next() {
+        let (len, style) = if c == '\'' || c == '"' {
+            let end = rest[1..].find(c).map_or(rest.len(), |i| i + 2);
+            (end, (c == '\'').then_some(STRING_STYLE))
+        } else if rest.starts_with("--") {
+            (rest.find('\n').unwrap_or(rest.len()), Some(COMMENT_STYLE))
+        } else if c.is_alphanumeric() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            let style = if word.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                Some(NUMBER_STYLE)
+            } else if KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(word)) {
+                Some(KEYWORD_STYLE)
+            } else {
+                None
+            };
+            (end, style)
+        } else {
+            (c.len_utf8(), None)
+        };
+        let (token, tail) = rest.split_at(len);
+        match style {
+            Some(style) => {
+                out.push_str(style);
+                out.push_str(token);
+                out.push_str(RESET);
+            }
+            None => out.push_str(token),
+        }
+        rest = tail;
+    }
+    out
+}
@@ -0,0 +1,818 @@
+mod helper;
+mod output;
+
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use helper::ShellHelper;
+use libparser::{Config, Engine, ExportFormat, LoadStats, LogQuery, QueryResult};
+use output::{Output, OutputMode};
+use rustyline::config::ColorMode;
+use rustyline::error::ReadlineError;
+use rustyline::history::FileHistory;
+use rustyline::Editor;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+
+/// Rows printed by `.view`; the total match count is reported separately.
+const VIEW_LIMIT: u32 = 1000;
+/// Sample lines `.dryrun` reads when not given a count.
+const DRY_RUN_LINES: usize = 10;
+/// Statements kept in the history file.
+const HISTORY_SIZE: usize = 1000;
+
+/// Dot-commands with their arguments and `.help` text.
+const COMMANDS: &[(&str, &str)] = &[
+    (".help", "Show this list"),
+    (
+        ".schema",
+        "Columns of the logs table and where their values come from",
+    ),
+    (".stats", "Statistics of the last file load"),
+    (".load FILE", "Load another log file into the logs table"),
+    (
+        ".rules FILE",
+        "Switch to another rule file and reload the loaded files",
+    ),
+    (
+        ".dryrun RULES [N]",
+        "Show what a rule file extracts from the first N lines of the last loaded file",
+    ),
+    (".view [NAME]", "List the views of the rules, or run one"),
+    (
+        ".cells [on|off]",
+        "Show or set showing views as the app's rendered cells",
+    ),
+    (
+        ".export csv|jsonl|raw|parquet|cells PATH [SELECT ...]",
+        "Export every log, or a query result, to a file",
+    ),
+    (".explain QUERY", "Show how SQLite runs a query"),
+    (
+        ".mode [aligned|vertical|csv|json|markdown]",
+        "Show or set the output mode",
+    ),
+    (".width [N|auto]", "Show or set the widest aligned column"),
+    (".pager [on|off]", "Show or set paging of long results"),
+    (".timer [on|off]", "Show or set printing of query times"),
+];
+
+#[derive(FromArgs)]
+/// Query a JSON log file with SQL. Starts an interactive shell unless
+/// statements are given with -e or -f or piped to stdin.
+#[argh(
+    error_code(1, "A statement failed."),
+    error_code(
+        2,
+        "The rules or the log file could not be loaded, or --check-rules found problems."
+    )
+)]
+struct Args {
+    #[argh(positional, description = "path to the log file")]
+    file: Option<String>,
+    #[argh(
+        option,
+        short = 'e',
+        description = "run this SQL statement or dot-command and exit; repeatable"
+    )]
+    execute: Vec<String>,
+    #[argh(
+        option,
+        short = 'f',
+        description = "run the statements in this file ('-' for stdin) and exit"
+    )]
+    script: Option<String>,
+    #[argh(option, description = "rule file to use instead of the built-in one")]
+    rules: Option<String>,
+    #[argh(
+        option,
+        short = 'm',
+        default = "OutputMode::Aligned",
+        description = "output mode: aligned, vertical, csv, json or markdown"
+    )]
+    mode: OutputMode,
+    #[argh(
+        option,
+        description = "check this rule file, print its problems and exit"
+    )]
+    check_rules: Option<String>,
+    #[argh(
+        option,
+        description = "print what the rules extract from the first N lines of the file and exit"
+    )]
+    dry_run: Option<usize>,
+    #[argh(
+        option,
+        description = "print rules suggested by the first N lines of the file and exit"
+    )]
+    infer_rules: Option<usize>,
+}
+
+/// The loaded engine plus the settings dot-commands change.
+struct Shell {
+    engine: Engine,
+    output: Output,
+    /// Every file loaded into `engine`, for `.rules` to reload.
+    files: Vec<String>,
+    last_load: Option<(String, LoadStats)>,
+    /// Show views as the rendered `[[col]]` cells instead of their columns.
+    cells: bool,
+}
+
+/// An error that ends logsql, with the exit code it ends with.
+struct Failure {
+    error: anyhow::Error,
+    code: u8,
+}
+
+impl Failure {
+    /// A statement failed.
+    fn statement(error: anyhow::Error) -> Self {
+        Failure { error, code: 1 }
+    }
+}
+
+/// The rules or the log file could not be loaded.
+impl From<anyhow::Error> for Failure {
+    fn from(error: anyhow::Error) -> Self {
+        Failure { error, code: 2 }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Args = argh::from_env();
+    match run(&args) {
+        Ok(code) => code,
+        Err(failure) => {
+            report(&failure.error);
+            ExitCode::from(failure.code)
+        }
+    }
+}
+
+/// Prints `error` with its causes to stderr, the way logsql reports every
+/// error.
+fn report(error: &anyhow::Error) {
+    eprintln!("Error: {:#}", error);
+}
+
+/// Runs the mode `args` select.
+fn run(args: &Args) -> Result<ExitCode, Failure> {
+    if let Some(path) = &args.check_rules {
+        return Ok(check_rules(&mut io::stdout(), path)?);
+    }
+    let Some(file) = &args.file else {
+        return Err(anyhow::anyhow!("Required positional argument not provided: file").into());
+    };
+    let interactive = args.execute.is_empty() && args.script.is_none() && io::stdin().is_terminal();
+    // Keep progress logs out of the way of scripted output.
+    let level = if interactive { "info" } else { "warn" };
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(level)).init();
+
+    let output = Output {
+        mode: args.mode,
+        max_width: None,
+        pager: interactive,
+        timer: true,
+    };
+    if let Some(limit) = args.infer_rules {
+        infer_rules(&mut io::stdout(), file, limit)?;
+    } else if let Some(limit) = args.dry_run {
+        let config = match &args.rules {
+            Some(path) => Config::from_file(path)?,
+            None => Config::load()?,
+        };
+        dry_run(&mut io::stdout(), &output, &config, file, limit)?;
+    } else {
+        let mut shell = Shell::open(args.rules.as_deref(), file, output)?;
+        run_statements(&mut shell, args, interactive).map_err(Failure::statement)?;
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Runs the REPL when `interactive`, otherwise the statements given with
+/// `-e` or `-f` or piped to stdin.
+fn run_statements(shell: &mut Shell, args: &Args, interactive: bool) -> Result<()> {
+    if interactive {
+        return shell.run_repl();
+    }
+    if !args.execute.is_empty() {
+        return args
+            .execute
+            .iter()
+            .try_for_each(|stmt| shell.run_statement(&mut io::stdout(), stmt));
+    }
+    match args.script.as_deref() {
+        None | Some("-") => shell.run_script(io::stdin().lock()),
+        Some(path) => File::open(path)
+            .with_context(|| format!("Failed to open script {:?}", path))
+            .and_then(|file| shell.run_script(BufReader::new(file))),
+    }
+}
+
+/// Prints rules suggested by the first `limit` lines of `file`.
+fn infer_rules(stdout: &mut impl Write, file: &str, limit: usize) -> Result<()> {
+    let lines = libparser::read_sample(file, limit)?;
+    write!(stdout, "{}", libparser::infer_schema(&lines).to_rules())?;
+    Ok(())
+}
+
+/// Prints every problem of the rule file at `path` like a compiler does,
+/// with the offending line underlined. Fails with exit code 2 when there
+/// are problems.
+fn check_rules(stdout: &mut impl Write, path: &str) -> Result<ExitCode> {
+    let source = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read rule file {:?}", path))?;
+    let diagnostics = Config::validate(&source);
+    for d in &diagnostics {
+        let line = source.lines().nth(d.line - 1).unwrap_or("");
+        let width = source[d.span.clone()]
+            .lines()
+            .next()
+            .map_or(1, |s| s.chars().count().max(1));
+        writeln!(stdout, "{}:{}:{}: {}", path, d.line, d.column, d.message)?;
+        writeln!(stdout, "    {}", line)?;
+        writeln!(
+            stdout,
+            "    {}{}",
+            " ".repeat(d.column - 1),
+            "^".repeat(width)
+        )?;
+    }
+    if diagnostics.is_empty() {
+        writeln!(stdout, "{}: OK", path)?;
+        Ok(ExitCode::SUCCESS)
+    } else {
+        writeln!(stdout, "{} problem(s) found", diagnostics.len())?;
+        Ok(ExitCode::from(2))
+    }
+}
+
+impl Shell {
+    /// Loads `file` with the rules at `rules`, or the built-in ones.
+    fn open(rules: Option<&str>, file: &str, output: Output) -> Result<Self> {
+        let mut shell = Shell {
+            engine: new_engine(rules)?,
+            output,
+            files: Vec::new(),
+            last_load: None,
+            cells: false,
+        };
+        shell.load_file(file)?;
+        log::info!(
+            "Table 'logs' is ready. Columns: {:?}",
+            shell.engine.columns()
+        );
+        Ok(shell)
+    }
+
+    fn load_file(&mut self, path: &str) -> Result<&LoadStats> {
+        let stats = self.engine.load_file(path)?;
+        log::info!(
+            "Loaded {} lines ({} not valid UTF-8). Read {:?} (JSON {:?}, saved ~{:?}), SQLite {:?}, FTS {:?}, Total {:?}",
+            stats.inserted_lines,
+            stats.malformed_lines,
+            stats.read_duration,
+            stats.parse_duration,
+            stats.parse_savings(),
+            stats.db_duration,
+            stats.fts_duration,
+            stats.total_duration
+        );
+        self.files.push(path.to_string());
+        Ok(&self.last_load.insert((path.to_string(), stats)).1)
+    }
+
+    fn run_repl(&mut self) -> Result<()> {
+        let config = rustyline::Config::builder()
+            .max_history_size(HISTORY_SIZE)?
+            .auto_add_history(false)
+            .color_mode(if std::env::var_os("NO_COLOR").is_some() {
+                ColorMode::Disabled
+            } else {
+                ColorMode::Enabled
+            })
+            .build();
+        let mut rl: Editor<ShellHelper, FileHistory> = Editor::with_config(config)?;
+        rl.set_helper(Some(ShellHelper::new(&self.engine)));
+        let history = history_path();
+        if let Some(path) = &history {
+            // Missing on the first run.
+            let _ = rl.load_history(path);
+        }
+
+        loop {
+            // The helper's validator keeps reading lines until a statement
+            // is complete, so each read is one whole statement.
+            match rl.readline("> ") {
+                Ok(stmt) => {
+                    if should_exit("", &stmt) {
+                        break;
+                    }
+                    if stmt.trim().is_empty() {
+                        continue;
+                    }
+                    rl.add_history_entry(stmt.trim())?;
+                    if let Err(e) = self.run_statement(&mut io::stdout(), &stmt) {
+                        report(&e);
+                    }
+                    if let Some(helper) = rl.helper_mut() {
+                        helper.refresh(&self.engine);
+                    }
+                }
+                Err(ReadlineError::Interrupted) => {
+                    log::info!("CTRL-C");
+                    break;
+                }
+                Err(ReadlineError::Eof) => {
+                    log::info!("CTRL-D");
+                    break;
+                }
+                Err(err) => {
+                    log::error!("Error: {:?}", err);
+                    break;
+                }
+            }
+        }
+        if let Some(path) = &history {
+            if let Err(e) = rl.save_history(path) {
+                log::warn!("Failed to save history to {:?}: {}", path, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the statements read from `input` and stops at the first that fails.
+    /// A trailing statement without `;` still runs.
+    fn run_script(&mut self, input: impl BufRead) -> Result<()> {
+        let mut input_buffer = String::new();
+        for line in input.lines() {
+            let line = line?;
+            if should_exit(&input_buffer, &line) {
+                return Ok(());
+            }
+            if let Some(stmt) = next_statement(&mut input_buffer, &line) {
+                self.run_statement(&mut io::stdout(), &stmt)?;
+            }
+        }
+        if !input_buffer.trim().is_empty() {
+            self.run_statement(&mut io::stdout(), &input_buffer)?;
+        }
+        Ok(())
+    }
+
+    fn run_statement(&mut self, stdout: &mut impl Write, stmt: &str) -> Result<()> {
+        let stmt = stmt.trim();
+        if stmt.starts_with('.') {
+            self.handle_command(stdout, stmt)
+        } else {
+            let result = self.engine.execute_query(stmt)?;
+            self.output.print(stdout, &result)
+        }
+    }
+
+    /// Dot-commands are single-line and need no trailing `;`.
+    fn handle_command(&mut self, stdout: &mut impl Write, line: &str) -> Result<()> {
+        let mut parts = line.split_whitespace();
+        match parts.next().unwrap_or_default() {
+            ".view" => match parts.next() {
+                Some(name) => self.run_view(stdout, name),
+                None => self.list_views(stdout),
+            },
+            ".mode" => {
+                if let Some(mode) = parts.next() {
+                    self.output.mode = mode.parse()?;
+                }
+                writeln!(stdout, "Output mode: {}", self.output.mode)?;
+                Ok(())
+            }
+            ".width" => {
+                match parts.next() {
+                    Some("auto") => self.output.max_width = None,
+                    Some(n) => {
+                        let width = n
+                            .parse()
+                            .ok()
+                            .filter(|&w| w > 0)
+                            .with_context(|| format!("Usage: .width N|auto, got '{}'", n))?;
+                        self.output.max_width = Some(width);
+                    }
+                    None => {}
+                }
+                match self.output.max_width {
+                    Some(width) => writeln!(stdout, "Column width: at most {}", width)?,
+                    None => writeln!(stdout, "Column width: fit the terminal")?,
+                }
+                Ok(())
+            }
+            ".pager" => {
+                match parts.next() {
+                    Some("on") => self.output.pager = true,
+                    Some("off") => self.output.pager = false,
+                    Some(other) => anyhow::bail!("Usage: .pager on|off, got '{}'", other),
+                    None => {}
+                }
+                let state = if self.output.pager { "on" } else { "off" };
+                writeln!(stdout, "Pager: {}", state)?;
+                Ok(())
+            }
+            ".help" => {
+                let width = COMMANDS.iter().map(|(c, _)| c.len()).max().unwrap_or(0);
+                for (command, help) in COMMANDS {
+                    writeln!(stdout, "{:width$}  {}", command, help)?;
+                }
+                writeln!(stdout, "{:width$}  Leave the shell", "exit, quit")?;
+                writeln!(stdout, "SQL statements end with ';' and may span lines.")?;
+                Ok(())
+            }
+            ".schema" => self.print_schema(stdout),
+            ".stats" => {
+                let Some((path, stats)) = &self.last_load else {
+                    anyhow::bail!("No file loaded");
+                };
+                print_stats(stdout, path, stats)
+            }
+            ".load" => {
+                let path = command_arg(line, ".load FILE")?;
+                let stats = self.load_file(path)?;
+                writeln!(
+                    stdout,
+                    "Loaded {} lines from {} (took {:?})",
+                    stats.inserted_lines, path, stats.total_duration
+                )?;
+                Ok(())
+            }
+            ".rules" => {
+                let path = command_arg(line, ".rules FILE")?;
+                self.reload(path)?;
+                writeln!(
+                    stdout,
+                    "Reloaded {} file(s) with {}. Columns: {}",
+                    self.files.len(),
+                    path,
+                    self.engine.columns().join(", ")
+                )?;
+                Ok(())
+            }
+            ".explain" => {
+                let query = command_arg(line, ".explain QUERY")?;
+                self.explain(stdout, query.trim_end_matches(';'))
+            }
+            ".timer" => {
+                match parts.next() {
+                    Some("on") => self.output.timer = true,
+                    Some("off") => self.output.timer = false,
+                    Some(other) => anyhow::bail!("Usage: .timer on|off, got '{}'", other),
+                    None => {}
+                }
+                let state = if self.output.timer { "on" } else { "off" };
+                writeln!(stdout, "Timer: {}", state)?;
+                Ok(())
+            }
+            ".cells" => {
+                match parts.next() {
+                    Some("on") => self.cells = true,
+                    Some("off") => self.cells = false,
+                    Some(other) => anyhow::bail!("Usage: .cells on|off, got '{}'", other),
+                    None => {}
+                }
+                let state = if self.cells { "on" } else { "off" };
+                writeln!(stdout, "Cells: {}", state)?;
+                Ok(())
+            }
+            ".dryrun" => {
+                let usage = ".dryrun RULES [N]";
+                let arg = command_arg(line, usage)?;
+                // A trailing number is the line count, the rest the path.
+                let (rules, limit) = match arg.rsplit_once(char::is_whitespace) {
+                    Some((rules, n)) if n.parse::<usize>().is_ok() => {
+                        (rules.trim_end(), n.parse()?)
+                    }
+                    _ => (arg, DRY_RUN_LINES),
+                };
+                let Some(file) = self.files.last() else {
+                    anyhow::bail!("No file loaded");
+                };
+                let config = Config::from_file(rules)?;
+                dry_run(stdout, &self.output, &config, file, limit)
+            }
+            ".export" => {
+                let rest = line[".export".len()..].trim_start();
+                let mut args = rest.splitn(3, char::is_whitespace);
+                match (args.next().filter(|a| !a.is_empty()), args.next()) {
+                    (Some(format), Some(path)) => {
+                        let sql = args.next().unwrap_or_default().trim().trim_end_matches(';');
+                        self.run_export(stdout, format, path, sql)
+                    }
+                    _ => anyhow::bail!(
+                        "Usage: .export csv|jsonl|raw|parquet|cells PATH [SELECT ...]"
+                    ),
+                }
+            }
+            other => anyhow::bail!("Unknown command: {}, see .help", other),
+        }
+    }
+
+    /// Loads every file again with the rules at `path`. The current engine
+    /// is kept if anything fails.
+    fn reload(&mut self, path: &str) -> Result<()> {
+        let mut engine = new_engine(Some(path))?;
+        let mut last_load = None;
+        for file in &self.files {
+            let stats = engine
+                .load_file(file)
+                .with_context(|| format!("Failed to reload {}", file))?;
+            last_load = Some((file.clone(), stats));
+        }
+        self.engine = engine;
+        self.last_load = last_load;
+        Ok(())
+    }
+
+    fn print_schema(&self, stdout: &mut impl Write) -> Result<()> {
+        let config = self.engine.config();
+        let rows = self
+            .engine
+            .columns()
+            .into_iter()
+            .map(|col| {
+                let (kind, source) = match col.as_str() {
+                    "id" => ("INTEGER", "line order".to_string()),
+                    "raw" => ("TEXT", "$line".to_string()),
+                    "ts" if config.time.is_some() => {
+                        let field = config.time.as_ref().map(|t| t.field.as_str());
+                        (
+                            "INTEGER",
+                            format!("{} as epoch milliseconds", field.unwrap_or_default()),
+                        )
+                    }
+                    other => ("TEXT", config.logs.get(other).cloned().unwrap_or_default()),
+                };
+                vec![Some(col), Some(kind.to_string()), Some(source)]
+            })
+            .collect();
+        let result = QueryResult {
+            headers: vec!["column".into(), "type".into(), "source".into()],
+            rows,
+            duration: Default::default(),
+        };
+        self.output.print_listing(stdout, &result)
+    }
+
+    /// Prints SQLite's plan for `query` as a tree, like the sqlite3 shell.
+    fn explain(&self, stdout: &mut impl Write, query: &str) -> Result<()> {
+        let plan = self
+            .engine
+            .execute_query(&format!("EXPLAIN QUERY PLAN {}", query))?;
+        // Rows are `id, parent, notused, detail`, parents before children.
+        fn text(v: &Option<String>) -> &str {
+            v.as_deref().unwrap_or_default()
+        }
+        let nodes: Vec<(&str, &str, &str)> = plan
+            .rows
+            .iter()
+            .map(|r| (text(&r[0]), text(&r[1]), text(&r[3])))
+            .collect();
+        writeln!(stdout, "QUERY PLAN")?;
+        write_plan(stdout, &nodes, "0", "")
+    }
+
+    fn list_views(&self, stdout: &mut impl Write) -> Result<()> {
+        let views = &self.engine.config().views;
+        if views.is_empty() {
+            writeln!(
+                stdout,
+                "No views defined, add a [[view]] section to the rules"
+            )?;
+        }
+        for view in views {
+            writeln!(stdout, "{}\t{}", view.name, view.filter)?;
+        }
+        Ok(())
+    }
+
+    fn run_view(&self, stdout: &mut impl Write, name: &str) -> Result<()> {
+        let Some(view) = self.engine.config().view(name) else {
+            anyhow::bail!("No view named '{}'", name);
+        };
+        let page = self.engine.get_logs(&view.query(VIEW_LIMIT, 0))?;
+        let mut result = page.result;
+        if self.cells {
+            if self.engine.config().layout.is_empty() {
+                anyhow::bail!("No cells to show, add a [[col]] section to the rules");
+            }
+            result.headers = self.engine.cell_headers();
+            result.rows = page
+                .cells
+                .into_iter()
+                .map(|row| row.into_iter().map(Some).collect())
+                .collect();
+        } else if !view.columns.is_empty() {
+            let mut indices = Vec::with_capacity(view.columns.len());
+            for col in &view.columns {
+                match result.headers.iter().position(|h| h == col) {
+                    Some(i) => indices.push(i),
+                    None => anyhow::bail!("Unknown column '{}' in view '{}'", col, name),
+                }
+            }
+            result.headers = view.columns.clone();
+            for row in &mut result.rows {
+                *row = indices
+                    .iter()
+                    .map(|&i| std::mem::take(&mut row[i]))
+                    .collect();
+            }
+        }
+        let shown = result.rows.len() as u64;
+        self.output.print(stdout, &result)?;
+        if page.total_count > shown {
+            writeln!(
+                stdout,
+                "({} rows match, showing the first {})",
+                page.total_count, shown
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Exports the result of `sql`, or every log when it is empty. `raw`
+    /// copies the stored bytes of the lines; every log as `raw` is
+    /// gzip-compressed when `path` ends in `.gz`.
+    fn run_export(
+        &self,
+        stdout: &mut impl Write,
+        format: &str,
+        path: &str,
+        sql: &str,
+    ) -> Result<()> {
+        let format = match format {
+            "csv" => ExportFormat::Csv,
+            "jsonl" => ExportFormat::Jsonl,
+            "raw" => ExportFormat::JsonlRaw,
+            "parquet" => ExportFormat::Parquet,
+            "cells" => ExportFormat::Cells,
+            other => anyhow::bail!("Unknown export format: {}", other),
+        };
+        let start = Instant::now();
+        let progress = |rows| eprint!("\r{} rows", rows);
+        let res = match format {
+            _ if !sql.is_empty() => self.engine.export_query(sql, format, path, progress),
+            ExportFormat::JsonlRaw => {
+                self.engine
+                    .export_raw(&LogQuery::default(), path, path.ends_with(".gz"), progress)
+            }
+            _ => self
+                .engine
+                .export_logs(&LogQuery::default(), format, path, progress),
+        };
+        eprintln!();
+        writeln!(
+            stdout,
+            "Exported {} rows to {} (took {:?})",
+            res?,
+            path,
+            start.elapsed()
+        )?;
+        Ok(())
+    }
+}
+
+/// `$LOGSQL_HISTORY`, or `.logsql_history` in the home directory.
+fn history_path() -> Option<PathBuf> {
+    match std::env::var_os("LOGSQL_HISTORY") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => std::env::home_dir().map(|home| home.join(".logsql_history")),
+    }
+}
+
+/// Prints the values `config` extracts from the first `limit` lines of
+/// `file`, then the columns that stayed empty with their paths.
+fn dry_run(
+    stdout: &mut impl Write,
+    output: &Output,
+    config: &Config,
+    file: &str,
+    limit: usize,
+) -> Result<()> {
+    let lines = libparser::read_sample(file, limit)?;
+    let run = config.dry_run(&lines);
+    let mut headers = vec!["line".to_string()];
+    headers.extend(run.columns.iter().cloned());
+    let rows = run
+        .rows
+        .into_iter()
+        .map(|row| {
+            let mut values = vec![Some(row.line_no.to_string())];
+            values.extend(row.values.into_iter().map(Some));
+            values
+        })
+        .collect();
+    let result = QueryResult {
+        headers,
+        rows,
+        duration: Default::default(),
+    };
+    output.print_listing(stdout, &result)?;
+    if !run.empty_columns.is_empty() {
+        writeln!(
+            stdout,
+            "Empty on all {} sample line(s), check their paths:",
+            result.rows.len()
+        )?;
+        for col in &run.empty_columns {
+            let path = config.logs.get(col).map_or("", String::as_str);
+            writeln!(stdout, "  {} = \"{}\"", col, path)?;
+        }
+    }
+    Ok(())
+}
+
+fn new_engine(rules: Option<&str>) -> Result<Engine> {
+    match rules {
+        Some(path) => Engine::new(Config::from_file(path)?),
+        None => Engine::from_embedded_config(),
+    }
+}
+
+/// The rest of a dot-command line after its name, or a usage error.
+fn command_arg<'a>(line: &'a str, usage: &str) -> Result<&'a str> {
+    let arg = line
+        .split_once(char::is_whitespace)
+        .map(|(_, rest)| rest.trim())
+        .unwrap_or_default();
+    if arg.is_empty() {
+        anyhow::bail!("Usage: {}", usage);
+    }
+    Ok(arg)
+}
+
+fn write_plan(
+    stdout: &mut impl Write,
+    nodes: &[(&str, &str, &str)],
+    parent: &str,
+    prefix: &str,
+) -> Result<()> {
+    let children: Vec<_> = nodes.iter().filter(|n| n.1 == parent).collect();
+    for (i, (id, _, detail)) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        writeln!(
+            stdout,
+            "{}{}{}",
+            prefix,
+            if last { "`--" } else { "|--" },
+            detail
+        )?;
+        let prefix = format!("{}{}", prefix, if last { "   " } else { "|  " });
+        write_plan(stdout, nodes, id, &prefix)?;
+    }
+    Ok(())
+}
+
+fn print_stats(stdout: &mut impl Write, path: &str, stats: &LoadStats) -> Result<()> {
+    writeln!(stdout, "File:    {}", path)?;
+    writeln!(
+        stdout,
+        "Lines:   {} ({} not valid UTF-8)",
+        stats.inserted_lines, stats.malformed_lines
+    )?;
+    writeln!(
+        stdout,
+        "Read:    {:?} (JSON {:?}, a full parse would take ~{:?})",
+        stats.read_duration, stats.parse_duration, stats.full_parse_estimate
+    )?;
+    writeln!(
+        stdout,
+        "SQLite:  {:?} (FTS {:?})",
+        stats.db_duration, stats.fts_duration
+    )?;
+    writeln!(stdout, "Total:   {:?}", stats.total_duration)?;
+    Ok(())
+}
+
+/// Adds `line` to `input_buffer` and returns the statement it completes: a
+/// dot-command is a single line, SQL runs up to a line ending in `;`.
+fn next_statement(input_buffer: &mut String, line: &str) -> Option<String> {
+    if input_buffer.is_empty() && line.trim_start().starts_with('.') {
+        return Some(line.trim().to_string());
+    }
+    if input_buffer.is_empty() && line.trim().is_empty() {
+        return None;
+    }
+    input_buffer.push_str(line);
+    input_buffer.push('\n');
+    if line.trim_end().ends_with(';') {
+        Some(std::mem::take(input_buffer))
+    } else {
+        None
+    }
+}
+
+fn should_exit(input_buffer: &str, line_buffer: &str) -> bool {
+    if !input_buffer.is_empty() {
+        return false;
+    }
+    let trimmed = line_buffer.trim();
+    trimmed.eq_ignore_ascii_case("exit") || trimmed.eq_ignore_ascii_case("quit")
+}
@@ -0,0 +1,335 @@
+use anyhow::Result;
+use libparser::QueryResult;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// How the human-readable modes show NULL. CSV leaves the field empty and
+/// JSON writes `null`.
+const NULL: &str = "NULL";
+
+/// Narrowest a column gets when the aligned table is shrunk to fit the
+/// terminal.
+const MIN_COLUMN_WIDTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// A table with columns padded to the same width and cut to fit.
+    Aligned,
+    /// One `column | value` block per row, for wide rows.
+    Vertical,
+    Csv,
+    /// A JSON array with one object per row, keys in column order.
+    Json,
+    /// A GitHub-flavored markdown table.
+    Markdown,
+}
+
+impl OutputMode {
+    pub const NAMES: &'static str = "aligned|vertical|csv|json|markdown";
+}
+
+impl FromStr for OutputMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "aligned" => OutputMode::Aligned,
+            "vertical" => OutputMode::Vertical,
+            "csv" => OutputMode::Csv,
+            "json" => OutputMode::Json,
+            "markdown" | "md" => OutputMode::Markdown,
+            other => anyhow::bail!("Unknown output mode '{}', expected {}", other, Self::NAMES),
+        })
+    }
+}
+
+impl fmt::Display for OutputMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OutputMode::Aligned => "aligned",
+            OutputMode::Vertical => "vertical",
+            OutputMode::Csv => "csv",
+            OutputMode::Json => "json",
+            OutputMode::Markdown => "markdown",
+        })
+    }
+}
+
+/// How query results are printed, changed with `.mode`, `.width`, `.pager`
+/// and `.timer`.
+pub struct Output {
+    pub mode: OutputMode,
+    /// Widest an aligned column may be. `None` shrinks the widest columns
+    /// until the table fits the terminal, and leaves them alone when stdout
+    /// is not a terminal.
+    pub max_width: Option<usize>,
+    /// Send results taller than the terminal through `$PAGER`.
+    pub pager: bool,
+    /// Print how long each query took.
+    pub timer: bool,
+}
+
+impl Output {
+    /// Prints `result`, followed by its row count (and duration, with the
+    /// timer on) in the human-readable modes.
+    pub fn print(&self, stdout: &mut impl Write, result: &QueryResult) -> Result<()> {
+        self.render(stdout, result, true)
+    }
+
+    /// Prints a table that is not a query result, such as `.schema`, without
+    /// the row count.
+    pub fn print_listing(&self, stdout: &mut impl Write, result: &QueryResult) -> Result<()> {
+        self.render(stdout, result, false)
+    }
+
+    fn render(&self, stdout: &mut impl Write, result: &QueryResult, footer: bool) -> Result<()> {
+        let terminal = terminal_size();
+        let mut buf = Vec::new();
+        match self.mode {
+            OutputMode::Aligned => {
+                let fit = terminal.filter(|_| self.max_width.is_none()).map(|t| t.0);
+                write_aligned(&mut buf, result, self.max_width, fit)?
+            }
+            OutputMode::Vertical => write_vertical(&mut buf, result)?,
+            OutputMode::Csv => write_csv(&mut buf, result)?,
+            OutputMode::Json => write_json(&mut buf, result)?,
+            OutputMode::Markdown => write_markdown(&mut buf, result)?,
+        }
+        let readable = matches!(
+            self.mode,
+            OutputMode::Aligned | OutputMode::Vertical | OutputMode::Markdown
+        );
+        if footer && readable {
+            if self.timer {
+                writeln!(
+                    buf,
+                    "({} rows, took {:?})",
+                    result.rows.len(),
+                    result.duration
+                )?;
+            } else {
+                writeln!(buf, "({} rows)", result.rows.len())?;
+            }
+        }
+
+        let lines = buf.iter().filter(|&&b| b == b'\n').count();
+        let paged = match terminal {
+            Some((_, height)) if self.pager && lines >= height => page(&buf)?,
+            _ => false,
+        };
+        if !paged {
+            stdout.write_all(&buf)?;
+        }
+        Ok(())
+    }
+}
+
+/// Columns and rows of the terminal stdout is attached to, if any.
+fn terminal_size() -> Option<(usize, usize)> {
+    if !io::stdout().is_terminal() {
+        return None;
+    }
+    let (cols, rows) = crossterm::terminal::size().ok()?;
+    Some((cols as usize, rows as usize))
+}
+
+/// Shows `text` in `$PAGER`, or `less` when it is unset, and waits for the
+/// user to close it. Returns false, having shown nothing, when the pager
+/// cannot be started.
+fn page(text: &[u8]) -> Result<bool> {
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| {
+        if cfg!(windows) {
+            "more".to_string()
+        } else {
+            "less -FRSX".to_string()
+        }
+    });
+    let mut args = pager.split_whitespace();
+    let program = args.next().unwrap_or("less");
+    let Ok(mut child) = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return Ok(false);
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager closing early (the user quit) is not an error.
+        let _ = stdin.write_all(text);
+    }
+    child.wait()?;
+    Ok(true)
+}
+
+/// `cap` limits every column, `fit` is the total width to shrink the table
+/// to.
+fn write_aligned(
+    out: &mut impl Write,
+    result: &QueryResult,
+    cap: Option<usize>,
+    fit: Option<usize>,
+) -> Result<()> {
+    let rows: Vec<Vec<Cow<str>>> = result
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|v| single_line(v.as_deref().unwrap_or(NULL)))
+                .collect()
+        })
+        .collect();
+    let mut widths: Vec<usize> = result.headers.iter().map(|h| h.width()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+    if let Some(cap) = cap {
+        for width in &mut widths {
+            *width = (*width).min(cap.max(1));
+        }
+    }
+    if let Some(fit) = fit {
+        let separators = 3 * widths.len().saturating_sub(1);
+        let mut total: usize = widths.iter().sum::<usize>() + separators;
+        while total > fit {
+            let Some(widest) = widths
+                .iter_mut()
+                .filter(|w| **w > MIN_COLUMN_WIDTH)
+                .max_by_key(|w| **w)
+            else {
+                break;
+            };
+            *widest -= 1;
+            total -= 1;
+        }
+    }
+
+    let headers: Vec<Cow<str>> = result
+        .headers
+        .iter()
+        .map(|h| Cow::from(h.as_str()))
+        .collect();
+    write_aligned_row(out, &headers, &widths)?;
+    let ruler: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    writeln!(out, "{}", ruler.join("-+-"))?;
+    for row in &rows {
+        write_aligned_row(out, row, &widths)?;
+    }
+    Ok(())
+}
+
+fn write_aligned_row(out: &mut impl Write, cells: &[Cow<str>], widths: &[usize]) -> Result<()> {
+    let mut line = String::new();
+    for (i, (cell, &width)) in cells.iter().zip(widths).enumerate() {
+        if i > 0 {
+            line.push_str(" | ");
+        }
+        let cell = truncate(cell, width);
+        line.push_str(&cell);
+        line.extend(std::iter::repeat_n(' ', width.saturating_sub(cell.width())));
+    }
+    writeln!(out, "{}", line.trim_end())?;
+    Ok(())
+}
+
+fn write_vertical(out: &mut impl Write, result: &QueryResult) -> Result<()> {
+    let label_width = result.headers.iter().map(|h| h.width()).max().unwrap_or(0);
+    for (i, row) in result.rows.iter().enumerate() {
+        writeln!(out, "-[ RECORD {} ]{}", i + 1, "-".repeat(label_width))?;
+        for (header, value) in result.headers.iter().zip(row) {
+            let pad = label_width - header.width();
+            writeln!(
+                out,
+                "{}{} | {}",
+                header,
+                " ".repeat(pad),
+                single_line(value.as_deref().unwrap_or(NULL))
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_csv(out: &mut impl Write, result: &QueryResult) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(&result.headers)?;
+    for row in &result.rows {
+        writer.write_record(row.iter().map(|v| v.as_deref().unwrap_or_default()))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_json(out: &mut impl Write, result: &QueryResult) -> Result<()> {
+    writeln!(out, "[")?;
+    for (i, row) in result.rows.iter().enumerate() {
+        // Written by hand so the keys keep the column order.
+        out.write_all(b"  {")?;
+        for (j, (header, value)) in result.headers.iter().zip(row).enumerate() {
+            if j > 0 {
+                out.write_all(b", ")?;
+            }
+            serde_json::to_writer(&mut *out, header)?;
+            out.write_all(b": ")?;
+            serde_json::to_writer(&mut *out, value)?;
+        }
+        let comma = if i + 1 < result.rows.len() { "," } else { "" };
+        writeln!(out, "}}{}", comma)?;
+    }
+    writeln!(out, "]")?;
+    Ok(())
+}
+
+fn write_markdown(out: &mut impl Write, result: &QueryResult) -> Result<()> {
+    let cell = |v: &str| v.replace('|', "\\|").replace('\n', "<br>");
+    let headers: Vec<String> = result.headers.iter().map(|h| cell(h)).collect();
+    writeln!(out, "| {} |", headers.join(" | "))?;
+    writeln!(out, "|{}", " --- |".repeat(headers.len()))?;
+    for row in &result.rows {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|v| cell(v.as_deref().unwrap_or(NULL)))
+            .collect();
+        writeln!(out, "| {} |", cells.join(" | "))?;
+    }
+    Ok(())
+}
+
+/// Replaces line breaks, tabs and other control characters with spaces so a
+/// value stays on one line.
+fn single_line(value: &str) -> Cow<'_, str> {
+    if value.chars().any(char::is_control) {
+        Cow::Owned(
+            value
+                .chars()
+                .map(|c| if c.is_control() { ' ' } else { c })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Cuts `value` to at most `width` columns, ending in `…` when shortened.
+fn truncate(value: &str, width: usize) -> Cow<'_, str> {
+    if value.width() <= width {
+        return Cow::Borrowed(value);
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in value.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push('…');
+    Cow::Owned(out)
+}
@@ -1,6 +1,10 @@
-use anyhow::Result;
+use crate::expr::Expr;
+use crate::query::{LogQuery, SortKey};
+use crate::search::SearchOptions;
+use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::path::Path;
 
 // #[derive(Embed)]
 // #[folder = "rules"]
@@ -11,6 +15,161 @@ const RULES: &str = include_str!("../rules/lkl2.toml");
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
     pub logs: HashMap<String, String>,
+    #[serde(default)]
+    pub time: Option<TimeConfig>,
+    #[serde(default, rename = "view")]
+    pub views: Vec<ViewConfig>,
+    /// The app's log list, one entry per `[[col]]` section, left to right.
+    #[serde(default, rename = "col")]
+    pub layout: Vec<ColumnConfig>,
+}
+
+/// Names the column holding the event time. Its ISO-8601 values are
+/// normalized into an extra `ts` column (epoch milliseconds).
+#[derive(Debug, Deserialize, Clone)]
+pub struct TimeConfig {
+    pub field: String,
+}
+
+/// A named preset from a `[[view]]` section: the filters and sort of a
+/// [`LogQuery`] plus the columns worth showing.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ViewConfig {
+    pub name: String,
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default)]
+    pub fts: String,
+    #[serde(default)]
+    pub search: SearchOptions,
+    #[serde(default)]
+    pub sort: Vec<SortKey>,
+    /// Columns to display, in order; empty means all of them.
+    #[serde(default)]
+    pub columns: Vec<String>,
+}
+
+/// A column of the app's log list. Its cells are stacked top to bottom.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(try_from = "ColumnToml")]
+pub struct ColumnConfig {
+    /// Fixed width in logical pixels.
+    pub width: Option<f64>,
+    /// Share of the width left by fixed columns. Columns with neither
+    /// `width` nor `flex` get a share of 1.
+    pub flex: Option<u32>,
+    pub rows: Vec<CellConfig>,
+}
+
+/// One `[[col.row]]` of a column.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CellConfig {
+    /// Display expression, e.g. `$eventName` or `time($eventTime)`.
+    pub expr: Expr,
+    #[serde(default)]
+    pub style: CellStyle,
+    #[serde(default = "default_max_lines", alias = "lines")]
+    pub max_lines: u32,
+    /// End text cut at `max_lines` with `…`.
+    #[serde(default = "default_ellipsis")]
+    pub ellipsis: bool,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CellStyle {
+    #[default]
+    Text,
+    /// Small grey text.
+    Meta,
+    /// Small text on a grey chip.
+    Tag,
+    /// Small text on a chip in the theme's primary color.
+    ColorTag,
+}
+
+/// A `[[col]]` section as written. `expr`, `style`, `maxLines` and
+/// `ellipsis` on the column itself are shorthand for a single row.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct ColumnToml {
+    width: Option<f64>,
+    flex: Option<u32>,
+    expr: Option<Expr>,
+    style: Option<CellStyle>,
+    #[serde(alias = "lines")]
+    max_lines: Option<u32>,
+    ellipsis: Option<bool>,
+    #[serde(default)]
+    row: Vec<CellConfig>,
+}
+
+impl TryFrom<ColumnToml> for ColumnConfig {
+    type Error = String;
+
+    fn try_from(col: ColumnToml) -> Result<Self, String> {
+        match (col.width, col.flex) {
+            (Some(_), Some(_)) => {
+                return Err("a column takes either width or flex, not both".into())
+            }
+            (Some(width), _) if !(width.is_finite() && width > 0.0) => {
+                return Err(format!("width must be a positive number, got {}", width))
+            }
+            (_, Some(0)) => return Err("flex must be at least 1".into()),
+            _ => {}
+        }
+        let has_shorthand = col.expr.is_some()
+            || col.style.is_some()
+            || col.max_lines.is_some()
+            || col.ellipsis.is_some();
+        let rows = match col.expr {
+            _ if has_shorthand && !col.row.is_empty() => {
+                return Err("set expr and style either on the column or on its [[col.row]]s".into())
+            }
+            Some(expr) => vec![CellConfig {
+                expr,
+                style: col.style.unwrap_or_default(),
+                max_lines: col.max_lines.unwrap_or_else(default_max_lines),
+                ellipsis: col.ellipsis.unwrap_or_else(default_ellipsis),
+            }],
+            None if col.row.is_empty() => {
+                return Err("a column needs an expr or at least one [[col.row]]".into())
+            }
+            None => col.row,
+        };
+        for cell in &rows {
+            if cell.max_lines == 0 {
+                return Err("maxLines must be at least 1".into());
+            }
+        }
+        Ok(ColumnConfig {
+            width: col.width,
+            flex: col.flex,
+            rows,
+        })
+    }
+}
+
+fn default_max_lines() -> u32 {
+    1
+}
+
+fn default_ellipsis() -> bool {
+    true
+}
+
+impl ViewConfig {
+    pub fn query(&self, limit: u32, offset: u32) -> LogQuery {
+        LogQuery {
+            filter_sql: self.filter.clone(),
+            fts_query: self.fts.clone(),
+            search_options: self.search,
+            sort: self.sort.clone(),
+            limit,
+            offset,
+        }
+    }
 }
 
 impl Config {
@@ -22,7 +181,101 @@ impl Config {
         //     )
         // })?;
         // let content = std::str::from_utf8(file.data.as_ref())?;
-        let config: Config = toml::from_str(RULES)?;
-        Ok(config)
+        Config::parse(RULES)
+    }
+
+    /// Loads a rule file in the same format as the embedded `lkl2.toml`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read rule file {:?}", path))?;
+        Config::parse(&content).with_context(|| format!("Invalid rule file {:?}", path))
+    }
+
+    pub fn view(&self, name: &str) -> Option<&ViewConfig> {
+        self.views.iter().find(|v| v.name == name)
+    }
+
+    /// The cells of the layout, column by column, top to bottom.
+    pub fn cells(&self) -> impl Iterator<Item = &CellConfig> {
+        self.layout.iter().flat_map(|col| &col.rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(cols: &str) -> Result<Vec<ColumnConfig>> {
+        Ok(Config::parse(&format!("[logs]\na = \"$line.a\"\n\n{}", cols))?.layout)
+    }
+
+    fn error(cols: &str) -> String {
+        format!("{:#}", layout(cols).unwrap_err())
+    }
+
+    #[test]
+    fn embedded_rules_parse() {
+        let config = Config::load().unwrap();
+        assert!(config.view("errors").is_some());
+        assert!(!config.layout.is_empty());
+    }
+
+    #[test]
+    fn column_shorthand_and_rows() {
+        let cols = layout(
+            "[[col]]\nwidth = 80\nexpr = \"$a\"\nstyle = \"tag\"\n\n\
+             [[col]]\nflex = 2\n\
+             [[col.row]]\nexpr = \"$a\"\nlines = 3\nellipsis = false\n\
+             [[col.row]]\nexpr = \"hex($a)\"\nstyle = \"colortag\"\n",
+        )
+        .unwrap();
+        assert_eq!(cols.len(), 2);
+        assert_eq!((cols[0].width, cols[0].flex), (Some(80.0), None));
+        assert_eq!(cols[0].rows.len(), 1);
+        assert_eq!(cols[0].rows[0].style, CellStyle::Tag);
+        assert_eq!(
+            (cols[0].rows[0].max_lines, cols[0].rows[0].ellipsis),
+            (1, true)
+        );
+        assert_eq!((cols[1].width, cols[1].flex), (None, Some(2)));
+        let rows = &cols[1].rows;
+        assert_eq!(
+            (rows[0].style, rows[0].max_lines, rows[0].ellipsis),
+            (CellStyle::Text, 3, false)
+        );
+        assert_eq!(rows[1].style, CellStyle::ColorTag);
+    }
+
+    #[test]
+    fn invalid_columns() {
+        let cases = [
+            (
+                "width = 10\nflex = 1\nexpr = \"$a\"",
+                "either width or flex",
+            ),
+            (
+                "width = 0\nexpr = \"$a\"",
+                "width must be a positive number",
+            ),
+            (
+                "width = -5.5\nexpr = \"$a\"",
+                "width must be a positive number",
+            ),
+            ("flex = 0\nexpr = \"$a\"", "flex must be at least 1"),
+            ("width = 10", "needs an expr or at least one [[col.row]]"),
+            ("expr = \"$a\"\nmaxLines = 0", "maxLines must be at least 1"),
+            (
+                "style = \"tag\"\n[[col.row]]\nexpr = \"$a\"",
+                "either on the column or on its",
+            ),
+            ("expr = \"$a\"\ncolor = \"red\"", "unknown field `color`"),
+            ("expr = \"$a\"\nstyle = \"bold\"", "unknown variant `bold`"),
+            ("expr = \"concat($a\"", "expected ',' or ')'"),
+        ];
+        for (col, expected) in cases {
+            let message = error(&format!("[[col]]\n{}\n", col));
+            assert!(message.contains(expected), "{}: {}", col, message);
+        }
     }
 }
@@ -1,29 +1,107 @@
 use crate::config::Config;
+use crate::DecodeMode;
 use anyhow::Result;
-use rusqlite::Connection;
+use memmap2::Mmap;
+use regex::Regex;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::hooks::{AuthAction, AuthContext, Authorization};
+use rusqlite::limits::Limit;
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{Connection, ErrorCode, OpenFlags};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Numbers the in-memory databases, so every [`Db`] gets its own.
+static NEXT_DB: AtomicU64 = AtomicU64::new(0);
+
+/// Converts an ISO-8601 time string into epoch milliseconds; NULL if unparsable.
+/// Rounded, since the fractional Julian day is not exact and truncating it
+/// would put whole seconds 1 ms early.
+const TS_EXPR: &str = "CAST(ROUND((julianday(?) - 2440587.5) * 86400000) AS INTEGER)";
 
 pub struct Db {
     pub conn: Connection,
     pub columns: Vec<String>,
+    /// Column whose values are normalized into `ts`, if configured.
+    pub time_field: Option<String>,
+    /// Whether `raw` is read from a mapped file rather than stored.
+    pub offsets: bool,
+    /// The URI of the in-memory database, which [`Db::reader`] opens again.
+    uri: String,
+    source: Option<RawSource>,
+    /// Opened by [`Db::reader`]: SQLite rejects every write.
+    pub read_only: bool,
+    /// Set while [`Db::create_index`] runs, the only time the authorizer of
+    /// [`Db::restrict_to_reads`] lets an index be created.
+    indexing: Arc<AtomicBool>,
+}
+
+/// The file a [`Db`] created with offset storage reads its `raw` lines from.
+#[derive(Clone)]
+pub struct RawSource {
+    pub map: Arc<Mmap>,
+    pub decode: DecodeMode,
+}
+
+/// What [`Db::insert_batch`] stores for the `raw` column of a row.
+pub enum RawValue {
+    /// The line itself.
+    Inline(Value),
+    /// Where the line sits in the [`RawSource`]; `text` only feeds the FTS
+    /// index and is not stored.
+    Offset { offset: i64, len: i64, text: String },
 }
 
 impl Db {
-    pub fn new(config: &Config) -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
+    /// With a `source`, `logs` stores `raw_offset` and `raw_len` and `raw` is
+    /// a virtual column that slices the line out of the source on access.
+    pub fn new(config: &Config, source: Option<RawSource>) -> Result<Self> {
+        // A shared-cache in-memory database lives until its last connection
+        // closes, so readers can keep using it after this `Db` is dropped.
+        let uri = format!(
+            "file:lkl2-{}-{}?mode=memory&cache=shared",
+            std::process::id(),
+            NEXT_DB.fetch_add(1, Ordering::Relaxed)
+        );
+        let conn = Connection::open(&uri)?;
 
         let mut columns: Vec<String> = config.logs.keys().cloned().collect();
         columns.sort();
 
+        let time_field = config.time.as_ref().map(|t| t.field.clone());
         for col in &columns {
             let lower = col.to_lowercase();
-            if lower == "id" || lower == "raw" {
+            if lower == "id"
+                || lower == "raw"
+                || (time_field.is_some() && lower == "ts")
+                || (source.is_some() && (lower == "raw_offset" || lower == "raw_len"))
+            {
                 anyhow::bail!("Column '{}' is reserved and cannot be redefined", col);
             }
         }
+        if let Some(field) = &time_field {
+            if !columns.contains(field) {
+                anyhow::bail!("Time field '{}' is not defined in [logs]", field);
+            }
+        }
 
-        let mut schema_parts = vec!["id INTEGER PRIMARY KEY".to_string(), "raw TEXT".to_string()];
+        let offsets = source.is_some();
+        let mut schema_parts = vec!["id INTEGER PRIMARY KEY".to_string()];
+        if let Some(source) = &source {
+            register_read_raw(&conn, source.clone())?;
+            schema_parts.push("raw_offset INTEGER".to_string());
+            schema_parts.push("raw_len INTEGER".to_string());
+            schema_parts.push(
+                "raw GENERATED ALWAYS AS (read_raw(raw_offset, raw_len)) VIRTUAL".to_string(),
+            );
+        } else {
+            schema_parts.push("raw TEXT".to_string());
+        }
         schema_parts.extend(columns.iter().map(|c| format!("{} TEXT", c)));
+        if time_field.is_some() {
+            schema_parts.push("ts INTEGER".to_string());
+        }
         let create_sql = format!("CREATE TABLE logs ({})", schema_parts.join(", "));
         conn.execute(&create_sql, [])?;
 
@@ -31,8 +109,82 @@ impl Db {
             "CREATE VIRTUAL TABLE logs_fts USING fts5(raw, content='logs', content_rowid='id', tokenize='trigram', detail='none')",
             [],
         )?;
+        register_regexp(&conn)?;
 
-        Ok(Db { conn, columns })
+        Ok(Db {
+            conn,
+            columns,
+            time_field,
+            offsets,
+            uri,
+            source,
+            read_only: false,
+            indexing: Arc::default(),
+        })
+    }
+
+    /// A second connection to the same rows that only reads, for a long
+    /// query such as an export to run on another thread while this `Db`
+    /// keeps serving. Writes through this `Db` fail while a statement of the
+    /// reader is running, see [`Db::create_index`] for indexes.
+    pub fn reader(&self) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            &self.uri,
+            OpenFlags::SQLITE_OPEN_READ_WRITE
+                | OpenFlags::SQLITE_OPEN_URI
+                | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        // In-memory databases ignore SQLITE_OPEN_READ_ONLY.
+        conn.pragma_update(None, "query_only", true)?;
+        if let Some(source) = &self.source {
+            register_read_raw(&conn, source.clone())?;
+        }
+        register_regexp(&conn)?;
+        Ok(Db {
+            conn,
+            columns: self.columns.clone(),
+            time_field: self.time_field.clone(),
+            offsets: self.offsets,
+            uri: self.uri.clone(),
+            source: self.source.clone(),
+            read_only: true,
+            indexing: Arc::default(),
+        })
+    }
+
+    /// Denies every statement that writes, other than the indexes of
+    /// [`Db::create_index`], and disables ATTACH, so that SQL from untrusted
+    /// clients can only read.
+    pub fn restrict_to_reads(&self) {
+        self.conn.set_limit(Limit::SQLITE_LIMIT_ATTACHED, 0);
+        let indexing = self.indexing.clone();
+        self.conn.authorizer(Some(move |ctx: AuthContext<'_>| {
+            authorize_read(ctx, indexing.load(Ordering::Relaxed))
+        }));
+    }
+
+    /// Creates the index `name` on `logs(terms)` unless it exists. Indexes
+    /// only make queries faster, so a reader creates none, and neither does
+    /// this `Db` while a statement of one of its readers locks the schema:
+    /// the query then scans `logs` instead.
+    pub fn create_index(&self, name: &str, terms: &str) -> Result<()> {
+        if self.read_only {
+            return Ok(());
+        }
+        let sql = format!("CREATE INDEX IF NOT EXISTS {} ON logs({})", name, terms);
+        self.indexing.store(true, Ordering::Relaxed);
+        let result = self.conn.execute(&sql, []);
+        self.indexing.store(false, Ordering::Relaxed);
+        match result {
+            Err(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::DatabaseLocked => {
+                log::info!("Not creating index {} while a reader is running", name);
+                Ok(())
+            }
+            result => {
+                result?;
+                Ok(())
+            }
+        }
     }
 
     pub fn rebuild_fts(&self) -> Result<()> {
@@ -41,33 +193,299 @@ impl Db {
         Ok(())
     }
 
-    pub fn insert_batch(&mut self, rows: &[(String, HashMap<String, String>)]) -> Result<()> {
+    /// Rows with [`RawValue::Offset`] are also added to the FTS index, which
+    /// then needs no [`Db::rebuild_fts`].
+    pub fn insert_batch(&mut self, rows: &[(RawValue, HashMap<String, String>)]) -> Result<()> {
         let tx = self.conn.transaction()?;
         {
-            let mut insert_columns = Vec::with_capacity(self.columns.len() + 1);
-            insert_columns.push("raw".to_string());
+            let mut insert_columns = Vec::with_capacity(self.columns.len() + 2);
+            if self.offsets {
+                insert_columns.push("raw_offset".to_string());
+                insert_columns.push("raw_len".to_string());
+            } else {
+                insert_columns.push("raw".to_string());
+            }
             insert_columns.extend(self.columns.iter().cloned());
-            let placeholders = vec!["?"; insert_columns.len()].join(",");
+            let mut placeholders = vec!["?"; insert_columns.len()];
+            if self.time_field.is_some() {
+                insert_columns.push("ts".to_string());
+                placeholders.push(TS_EXPR);
+            }
             let sql = format!(
                 "INSERT INTO logs ({}) VALUES ({})",
                 insert_columns.join(","),
-                placeholders
+                placeholders.join(",")
             );
             let mut stmt = tx.prepare(&sql)?;
+            let mut fts_stmt = tx.prepare("INSERT INTO logs_fts(rowid, raw) VALUES (?, ?)")?;
 
             for (raw, row) in rows {
-                let mut values: Vec<rusqlite::types::Value> =
-                    Vec::with_capacity(insert_columns.len());
-                values.push(raw.clone().into());
+                let mut values: Vec<Value> = Vec::with_capacity(insert_columns.len());
+                match raw {
+                    RawValue::Inline(value) => values.push(value.clone()),
+                    RawValue::Offset { offset, len, .. } => {
+                        values.push(Value::Integer(*offset));
+                        values.push(Value::Integer(*len));
+                    }
+                }
 
                 for col in &self.columns {
                     let val = row.get(col).cloned().unwrap_or_default();
                     values.push(val.into());
                 }
+                if let Some(field) = &self.time_field {
+                    values.push(row.get(field).cloned().unwrap_or_default().into());
+                }
                 stmt.execute(rusqlite::params_from_iter(values))?;
+                if let RawValue::Offset { text, .. } = raw {
+                    fts_stmt.execute(rusqlite::params![tx.last_insert_rowid(), text])?;
+                }
             }
         }
         tx.commit()?;
         Ok(())
     }
 }
+
+/// Allows reading and querying pragmas. While `indexing`, it also allows
+/// creating an index, which writes its row into `sqlite_master` and fills it
+/// like REINDEX.
+fn authorize_read(ctx: AuthContext<'_>, indexing: bool) -> Authorization {
+    match ctx.action {
+        AuthAction::Select
+        | AuthAction::Read { .. }
+        | AuthAction::Function { .. }
+        | AuthAction::Recursive
+        | AuthAction::Pragma {
+            pragma_value: None, ..
+        } => Authorization::Allow,
+        AuthAction::CreateIndex { .. }
+        | AuthAction::Reindex { .. }
+        | AuthAction::Insert {
+            table_name: "sqlite_master",
+        } if indexing => Authorization::Allow,
+        _ => Authorization::Deny,
+    }
+}
+
+/// A column value as text, `None` for NULL. Blobs are only in `raw`, for
+/// lines that are not valid UTF-8, and are decoded lossily.
+pub(crate) fn value_text(value: ValueRef<'_>) -> Option<String> {
+    match value {
+        ValueRef::Null => None,
+        ValueRef::Integer(i) => Some(i.to_string()),
+        ValueRef::Real(f) => Some(f.to_string()),
+        ValueRef::Text(t) | ValueRef::Blob(t) => Some(String::from_utf8_lossy(t).into_owned()),
+    }
+}
+
+/// Backs the virtual `raw` column under offset storage: `read_raw(offset,
+/// len)` returns those bytes of the source, decoded as `load_file` would have
+/// stored them, or NULL when they are not all in the source (including
+/// negative arguments, which SQL can pass). Deterministic because the file is
+/// assumed not to change while it is mapped.
+fn register_read_raw(conn: &Connection, source: RawSource) -> Result<()> {
+    conn.create_scalar_function(
+        "read_raw",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        move |ctx| {
+            let offset = usize::try_from(ctx.get::<i64>(0)?);
+            let len = usize::try_from(ctx.get::<i64>(1)?);
+            let bytes = match (offset, len) {
+                (Ok(offset), Ok(len)) => offset
+                    .checked_add(len)
+                    .and_then(|end| source.map.get(offset..end)),
+                _ => None,
+            };
+            let Some(bytes) = bytes else {
+                return Ok(Value::Null);
+            };
+            Ok(match (source.decode, std::str::from_utf8(bytes)) {
+                (DecodeMode::Raw, Ok(text)) => Value::Text(text.to_string()),
+                (DecodeMode::Raw, Err(_)) => Value::Blob(bytes.to_vec()),
+                (DecodeMode::Lossy, _) => {
+                    let text = String::from_utf8_lossy(bytes);
+                    Value::Text(text.strip_suffix('\r').unwrap_or(&text).to_string())
+                }
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// Backs SQLite's `X REGEXP Y` operator, which calls `regexp(Y, X)`. The
+/// compiled pattern is cached per statement; NULL values never match and
+/// blobs (`raw` lines that are not valid UTF-8) are matched lossily.
+fn register_regexp(conn: &Connection) -> Result<()> {
+    conn.create_scalar_function(
+        "regexp",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let regex = ctx.get_or_create_aux(
+                0,
+                |pattern| -> Result<Regex, Box<dyn std::error::Error + Send + Sync>> {
+                    Ok(Regex::new(pattern.as_str()?)?)
+                },
+            )?;
+            Ok(match ctx.get_raw(1) {
+                ValueRef::Text(t) | ValueRef::Blob(t) => {
+                    regex.is_match(&String::from_utf8_lossy(t))
+                }
+                ValueRef::Integer(i) => regex.is_match(&i.to_string()),
+                ValueRef::Real(f) => regex.is_match(&f.to_string()),
+                ValueRef::Null => false,
+            })
+        },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::{LogQuery, SortKey};
+    use crate::{test_util, Engine};
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+t = "$line.t"
+
+[time]
+field = "t"
+"#;
+
+    fn restricted() -> Engine {
+        let engine = test_util::engine(
+            RULES,
+            &[
+                r#"{"level":"Warn","t":"2026-01-01T00:00:00Z"}"#,
+                r#"{"level":"Error","t":"2026-01-01T00:00:01Z"}"#,
+            ],
+        );
+        engine.restrict_to_reads();
+        engine
+    }
+
+    #[test]
+    fn ts_is_exact_epoch_millis() {
+        let times = [
+            "2026-01-01T00:00:00Z",
+            "2026-01-01T00:00:01Z",
+            "2026-01-01T00:00:01.001Z",
+            "2026-01-01T00:00:59.999Z",
+            "2026-01-01T08:00:01+08:00",
+            "1969-12-31T23:59:59Z",
+            "not a time",
+        ];
+        let lines: Vec<String> = times
+            .iter()
+            .map(|t| format!(r#"{{"level":"Info","t":"{}"}}"#, t))
+            .collect();
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+        let engine = test_util::engine(RULES, &lines);
+        let ts: Vec<Option<String>> = engine
+            .execute_query("SELECT ts FROM logs ORDER BY id")
+            .unwrap()
+            .rows
+            .into_iter()
+            .map(|mut row| row.remove(0))
+            .collect();
+        let expected = [
+            Some("1767225600000"),
+            Some("1767225601000"),
+            Some("1767225601001"),
+            Some("1767225659999"),
+            Some("1767225601000"),
+            Some("-1000"),
+            None,
+        ];
+        assert_eq!(ts, expected.map(|t| t.map(String::from)));
+    }
+
+    #[test]
+    fn restricted_engine_still_queries() {
+        let engine = restricted();
+        let query = LogQuery {
+            filter_sql: "level != 'Info'".to_string(),
+            fts_query: "Error".to_string(),
+            sort: vec![SortKey {
+                column: "level".to_string(),
+                descending: true,
+                sort_type: Default::default(),
+            }],
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(engine.get_logs(&query).unwrap().total_count, 1);
+        assert_eq!(
+            engine
+                .get_histogram(&query, 10)
+                .unwrap()
+                .counts
+                .iter()
+                .sum::<u64>(),
+            1
+        );
+        assert_eq!(engine.get_facets("level", &query, 10).unwrap().len(), 1);
+        // Too short for the trigram index, so rows are scanned with REGEXP.
+        let scan = LogQuery {
+            fts_query: "Wa".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(engine.get_logs(&scan).unwrap().total_count, 1);
+        assert!(engine.execute_query("PRAGMA query_only").is_ok());
+        let indexes = engine
+            .execute_query(
+                "SELECT name FROM sqlite_master WHERE name LIKE 'logs_%' AND type = 'index' ORDER BY name",
+            )
+            .unwrap();
+        let names: Vec<_> = indexes
+            .rows
+            .iter()
+            .map(|row| row[0].as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "logs_sort_5level_text_asc",
+                "logs_sort_5level_text_desc",
+                "logs_ts"
+            ]
+        );
+    }
+
+    #[test]
+    fn restricted_engine_rejects_writes() {
+        let engine = restricted();
+        for sql in [
+            "DELETE FROM logs",
+            "UPDATE logs SET level = 'x'",
+            "INSERT INTO logs(level) VALUES ('x')",
+            "DROP TABLE logs",
+            "CREATE TABLE copy AS SELECT * FROM logs",
+            "ATTACH DATABASE '/tmp/lkl2-attach.db' AS other",
+            "PRAGMA query_only = 0",
+            "BEGIN",
+            "CREATE INDEX big ON logs(level || t)",
+            "CREATE INDEX logs_sort_5level_text_asc ON logs(level)",
+            "REINDEX",
+        ] {
+            assert!(engine.execute_query(sql).is_err(), "{}", sql);
+        }
+        let filter = LogQuery {
+            filter_sql: "id IN (SELECT id FROM logs) OR 1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(engine.get_logs(&filter).unwrap().total_count, 2);
+        assert_eq!(
+            engine
+                .execute_query("SELECT COUNT(*) FROM logs")
+                .unwrap()
+                .rows[0][0]
+                .as_deref(),
+            Some("2")
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use crate::db::value_text;
+use crate::query::LogQuery;
+use crate::Engine;
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
+use parquet::data_type::{ByteArray, ByteArrayType};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::types::Type;
+use rusqlite::types::ValueRef;
+use rusqlite::Statement;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// `progress` is called after every this many rows, and once at the end.
+const PROGRESS_INTERVAL: u64 = 10_000;
+/// Rows buffered per Parquet row group.
+const PARQUET_ROW_GROUP: usize = 65_536;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Header line plus one record per row; NULL becomes an empty field.
+    Csv,
+    /// One JSON object per row, keyed by column name in column order.
+    Jsonl,
+    /// The bytes of the `raw` column as stored, one line per row, like
+    /// [`Engine::export_raw`]. For JSON logs loaded with
+    /// [`DecodeMode::Raw`](crate::DecodeMode::Raw) this is the original JSON
+    /// Lines input.
+    JsonlRaw,
+    /// A single file with every column as an optional UTF-8 string.
+    Parquet,
+    /// CSV of the `[[col]]` cells as the app shows them, one column per
+    /// cell, headed by its expression.
+    Cells,
+}
+
+impl Engine {
+    /// Writes every row matching `query`'s filter and full text search, in its
+    /// sort order, to `path`. `query`'s limit and offset are ignored. The
+    /// columns are `id` and the configured columns (`raw` for
+    /// [`ExportFormat::JsonlRaw`]). Returns the number of rows written.
+    pub fn export_logs(
+        &self,
+        query: &LogQuery,
+        format: ExportFormat,
+        path: impl AsRef<Path>,
+        progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        self.check_sort(&query.sort)?;
+        self.ensure_sort_index(&query.sort)?;
+        let cols = if format == ExportFormat::JsonlRaw {
+            "raw".to_string()
+        } else {
+            self.select_list()
+        };
+        let sql = format!(
+            "SELECT {} FROM logs {} {}",
+            cols,
+            query.where_clause()?,
+            query.order_clause()
+        );
+        self.export_query(&sql, format, path, progress)
+    }
+
+    /// Writes the `raw` line of every row matching `query`'s filter and full
+    /// text search to `path`, in load order, copying the stored bytes
+    /// unchanged. With `gzip` the file is gzip-compressed. `query`'s sort,
+    /// limit and offset are ignored. Returns the number of lines written.
+    pub fn export_raw(
+        &self,
+        query: &LogQuery,
+        path: impl AsRef<Path>,
+        gzip: bool,
+        progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        let sql = format!("SELECT raw FROM logs {} ORDER BY id", query.where_clause()?);
+        let mut stmt = self.db.conn.prepare(&sql)?;
+        let path = path.as_ref();
+        let file = File::create(path).with_context(|| format!("Failed to create {:?}", path))?;
+        let out = BufWriter::new(file);
+        if gzip {
+            let mut out = GzEncoder::new(out, Compression::default());
+            let written = copy_raw(&mut stmt, 0, &mut out, progress)?;
+            out.finish()?.flush()?;
+            Ok(written)
+        } else {
+            let mut out = out;
+            let written = copy_raw(&mut stmt, 0, &mut out, progress)?;
+            out.flush()?;
+            Ok(written)
+        }
+    }
+
+    /// Streams the result of an arbitrary SQL query to `path`. For
+    /// [`ExportFormat::JsonlRaw`] the query must return a `raw` column, for
+    /// [`ExportFormat::Cells`] the columns the cells read. Returns the number
+    /// of rows written.
+    pub fn export_query(
+        &self,
+        sql: &str,
+        format: ExportFormat,
+        path: impl AsRef<Path>,
+        mut progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        let mut stmt = self.db.conn.prepare(sql)?;
+        let headers: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+        let raw_column = headers.iter().position(|h| h == "raw");
+        if format == ExportFormat::JsonlRaw && raw_column.is_none() {
+            anyhow::bail!("The query has no 'raw' column to export");
+        }
+        let renderer = if format == ExportFormat::Cells {
+            if self.config.layout.is_empty() {
+                anyhow::bail!("No cells to export, add a [[col]] section to the rules");
+            }
+            Some(self.renderer(&headers))
+        } else {
+            None
+        };
+        let path = path.as_ref();
+        let file = File::create(path).with_context(|| format!("Failed to create {:?}", path))?;
+        if let (ExportFormat::JsonlRaw, Some(column)) = (format, raw_column) {
+            let mut out = BufWriter::new(file);
+            let written = copy_raw(&mut stmt, column, &mut out, progress)?;
+            out.flush()?;
+            return Ok(written);
+        }
+        let mut writer: Box<dyn RowWriter> = match format {
+            ExportFormat::Csv => Box::new(CsvWriter::new(file, &headers)?),
+            ExportFormat::Jsonl => Box::new(JsonlWriter {
+                out: BufWriter::new(file),
+                headers: headers.clone(),
+            }),
+            ExportFormat::JsonlRaw => unreachable!("raw lines are copied above"),
+            ExportFormat::Parquet => Box::new(ParquetWriter::new(file, &headers)?),
+            ExportFormat::Cells => Box::new(CsvWriter::new(file, &self.cell_headers())?),
+        };
+
+        let mut rows = stmt.query([])?;
+        let mut written = 0u64;
+        let mut values: Vec<Option<String>> = Vec::with_capacity(headers.len());
+        while let Some(row) = rows.next()? {
+            values.clear();
+            for i in 0..headers.len() {
+                values.push(value_text(row.get_ref(i)?));
+            }
+            match &renderer {
+                Some(renderer) => {
+                    let cells: Vec<Option<String>> =
+                        renderer.render(&values).into_iter().map(Some).collect();
+                    writer.write_row(&cells)?;
+                }
+                None => writer.write_row(&values)?,
+            }
+            written += 1;
+            if written.is_multiple_of(PROGRESS_INTERVAL) {
+                progress(written);
+            }
+        }
+        writer.finish()?;
+        progress(written);
+        Ok(written)
+    }
+}
+
+/// Writes the bytes of result column `column` of every row, each followed by
+/// `\n`; NULL gives an empty line.
+fn copy_raw(
+    stmt: &mut Statement<'_>,
+    column: usize,
+    out: &mut impl Write,
+    mut progress: impl FnMut(u64),
+) -> Result<u64> {
+    let mut rows = stmt.query([])?;
+    let mut written = 0u64;
+    while let Some(row) = rows.next()? {
+        match row.get_ref(column)? {
+            ValueRef::Text(bytes) | ValueRef::Blob(bytes) => out.write_all(bytes)?,
+            _ => {}
+        }
+        out.write_all(b"\n")?;
+        written += 1;
+        if written.is_multiple_of(PROGRESS_INTERVAL) {
+            progress(written);
+        }
+    }
+    progress(written);
+    Ok(written)
+}
+
+trait RowWriter {
+    fn write_row(&mut self, values: &[Option<String>]) -> Result<()>;
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+struct CsvWriter {
+    out: csv::Writer<File>,
+}
+
+impl CsvWriter {
+    fn new(file: File, headers: &[String]) -> Result<Self> {
+        let mut out = csv::Writer::from_writer(file);
+        out.write_record(headers)?;
+        Ok(CsvWriter { out })
+    }
+}
+
+impl RowWriter for CsvWriter {
+    fn write_row(&mut self, values: &[Option<String>]) -> Result<()> {
+        self.out
+            .write_record(values.iter().map(|v| v.as_deref().unwrap_or_default()))?;
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+struct JsonlWriter {
+    out: BufWriter<File>,
+    headers: Vec<String>,
+}
+
+impl RowWriter for JsonlWriter {
+    fn write_row(&mut self, values: &[Option<String>]) -> Result<()> {
+        // Written by hand so the keys keep the column order.
+        self.out.write_all(b"{")?;
+        for (i, (header, value)) in self.headers.iter().zip(values).enumerate() {
+            if i > 0 {
+                self.out.write_all(b",")?;
+            }
+            serde_json::to_writer(&mut self.out, header)?;
+            self.out.write_all(b":")?;
+            serde_json::to_writer(&mut self.out, value)?;
+        }
+        self.out.write_all(b"}\n")?;
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+/// Buffers rows column by column and flushes them as one row group every
+/// [`PARQUET_ROW_GROUP`] rows.
+struct ParquetWriter {
+    writer: SerializedFileWriter<File>,
+    columns: Vec<Vec<Option<String>>>,
+}
+
+impl ParquetWriter {
+    fn new(file: File, headers: &[String]) -> Result<Self> {
+        let fields = headers
+            .iter()
+            .map(|h| {
+                Type::primitive_type_builder(h, PhysicalType::BYTE_ARRAY)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_logical_type(Some(LogicalType::String))
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<parquet::errors::Result<Vec<_>>>()?;
+        let schema = Type::group_type_builder("logs")
+            .with_fields(fields)
+            .build()?;
+        let props = WriterProperties::builder().build();
+        let writer = SerializedFileWriter::new(file, Arc::new(schema), Arc::new(props))?;
+        Ok(ParquetWriter {
+            writer,
+            columns: vec![Vec::new(); headers.len()],
+        })
+    }
+
+    fn flush_row_group(&mut self) -> Result<()> {
+        if self.columns.first().is_none_or(Vec::is_empty) {
+            return Ok(());
+        }
+        let mut row_group = self.writer.next_row_group()?;
+        for column in &mut self.columns {
+            let mut values = Vec::with_capacity(column.len());
+            let mut def_levels = Vec::with_capacity(column.len());
+            for value in column.drain(..) {
+                match value {
+                    Some(v) => {
+                        values.push(ByteArray::from(v.into_bytes()));
+                        def_levels.push(1);
+                    }
+                    None => def_levels.push(0),
+                }
+            }
+            let mut col_writer = row_group
+                .next_column()?
+                .context("Parquet schema has fewer columns than the query")?;
+            col_writer
+                .typed::<ByteArrayType>()
+                .write_batch(&values, Some(&def_levels), None)?;
+            col_writer.close()?;
+        }
+        row_group.close()?;
+        Ok(())
+    }
+}
+
+impl RowWriter for ParquetWriter {
+    fn write_row(&mut self, values: &[Option<String>]) -> Result<()> {
+        for (column, value) in self.columns.iter_mut().zip(values) {
+            column.push(value.clone());
+        }
+        if self.columns[0].len() >= PARQUET_ROW_GROUP {
+            self.flush_row_group()?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        self.flush_row_group()?;
+        self.writer.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::SortKey;
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+t = "$line.t"
+
+[time]
+field = "t"
+"#;
+
+    fn engine() -> Engine {
+        test_util::engine(
+            RULES,
+            &[
+                r#"{"level":"Warn","t":"2026-01-01T00:00:00Z"}"#,
+                r#"{"level":"Error","t":"2026-01-01T00:00:01Z"}"#,
+                r#"{"level":"Info","t":"2026-01-01T00:00:02Z"}"#,
+            ],
+        )
+    }
+
+    fn by_level() -> LogQuery {
+        LogQuery {
+            sort: vec![SortKey {
+                column: "level".to_string(),
+                descending: false,
+                sort_type: Default::default(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reader_exports_sorted_rows_without_an_index() {
+        let reader = engine().reader().unwrap();
+        let path = std::env::temp_dir().join(format!("lkl2-reader-{}.csv", std::process::id()));
+        let written = reader
+            .export_logs(&by_level(), ExportFormat::Csv, &path, |_| {})
+            .unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, 3);
+        assert_eq!(
+            csv,
+            "id,level,t\n\
+             2,Error,2026-01-01T00:00:01Z\n\
+             3,Info,2026-01-01T00:00:02Z\n\
+             1,Warn,2026-01-01T00:00:00Z\n"
+        );
+    }
+
+    #[test]
+    fn raw_exports_copy_the_stored_bytes() {
+        let input: &[u8] = b"{\"level\":\"Warn\"}\r\n{\"level\":\"\xff\"}\n";
+        let mut engine = Engine::new(crate::Config::parse(RULES).unwrap()).unwrap();
+        let options = crate::LoadOptions {
+            decode: crate::DecodeMode::Raw,
+            ..Default::default()
+        };
+        engine.load_reader(input, &options).unwrap();
+        let path = std::env::temp_dir().join(format!("lkl2-raw-{}.jsonl", std::process::id()));
+        let query = LogQuery::default();
+
+        engine
+            .export_logs(&query, ExportFormat::JsonlRaw, &path, |_| {})
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), input);
+        engine
+            .export_query(
+                "SELECT id, raw FROM logs ORDER BY id",
+                ExportFormat::JsonlRaw,
+                &path,
+                |_| {},
+            )
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), input);
+        engine.export_raw(&query, &path, false, |_| {}).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), input);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reader_rejects_writes() {
+        let reader = engine().reader().unwrap();
+        assert!(reader.execute_query("DELETE FROM logs").is_err());
+        assert_eq!(
+            reader
+                .execute_query("SELECT id FROM logs")
+                .unwrap()
+                .rows
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn engine_serves_while_a_reader_streams() {
+        let engine = engine();
+        let reader = engine.reader().unwrap();
+        let mut stmt = reader.db.conn.prepare("SELECT id FROM logs").unwrap();
+        let mut rows = stmt.query([]).unwrap();
+        rows.next().unwrap();
+        let page = engine.get_logs(&LogQuery::default()).unwrap();
+        assert_eq!(page.total_count, 3);
+    }
+
+    #[test]
+    fn engine_skips_new_indexes_while_a_reader_streams() {
+        let engine = engine();
+        let reader = engine.reader().unwrap();
+        let mut stmt = reader.db.conn.prepare("SELECT id FROM logs").unwrap();
+        let mut rows = stmt.query([]).unwrap();
+        rows.next().unwrap();
+
+        // Each of these would create an index, which the reader's statement
+        // blocks; they scan instead.
+        let page = engine
+            .get_logs(&LogQuery {
+                limit: 10,
+                ..by_level()
+            })
+            .unwrap();
+        let levels: Vec<_> = page
+            .result
+            .rows
+            .iter()
+            .map(|row| row[1].as_deref().unwrap())
+            .collect();
+        assert_eq!(levels, ["Error", "Info", "Warn"]);
+        let facets = engine.get_facets("t", &LogQuery::default(), 10).unwrap();
+        assert_eq!(facets.len(), 3);
+        let histogram = engine.get_histogram(&LogQuery::default(), 10).unwrap();
+        assert_eq!(histogram.counts.iter().sum::<u64>(), 3);
+
+        // Once the reader is done, the indexes are made as usual.
+        drop(rows);
+        drop(stmt);
+        engine.get_logs(&by_level()).unwrap();
+        let indexes = engine
+            .execute_query("SELECT name FROM sqlite_master WHERE name LIKE 'logs_sort%'")
+            .unwrap();
+        assert_eq!(indexes.rows.len(), 1);
+    }
+}
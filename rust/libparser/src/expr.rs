@@ -0,0 +1,508 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+use serde::Deserialize;
+use std::fmt;
+
+/// The display expression of a `[[col]]` cell, rendering a row into the text
+/// shown in the app's log list.
+///
+/// ```text
+/// $eventName                          a column of the row; empty if missing
+/// "text", 'text', 42                  literals
+/// time($eventTime)                    ISO-8601 time or epoch seconds/millis
+///                                     as local "YYYY-MM-DD HH:MM:SS"
+/// substr($id, 0, 4)                   chars [start, start + len); a negative
+///                                     start counts from the end
+/// hex($sourceNodeId)                  an integer in lowercase hexadecimal
+/// concat($a, ":", $b)                 the arguments joined
+/// if($a == "Error", "!", $b)          the second argument when the first is
+///                                     true, otherwise the third
+/// ```
+///
+/// Every value is text; `==` and `!=` give `"true"` or `""`, and every value
+/// but `""`, `"0"` and `"false"` (in any case) is true. Expressions are
+/// parsed when the rules are loaded, so syntax errors surface there.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Expr {
+    source: String,
+    node: Node,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Field(String),
+    Text(String),
+    Call(Func, Vec<Node>),
+    Compare {
+        equal: bool,
+        lhs: Box<Node>,
+        rhs: Box<Node>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Func {
+    Time,
+    Substr,
+    Hex,
+    Concat,
+    If,
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "time" => Func::Time,
+            "substr" => Func::Substr,
+            "hex" => Func::Hex,
+            "concat" => Func::Concat,
+            "if" => Func::If,
+            _ => return None,
+        })
+    }
+
+    /// Smallest and largest number of arguments.
+    fn arity(self) -> (usize, usize) {
+        match self {
+            Func::Time | Func::Hex => (1, 1),
+            Func::Substr => (2, 3),
+            Func::Concat => (1, usize::MAX),
+            Func::If => (3, 3),
+        }
+    }
+}
+
+/// A syntax error at byte `offset` of the expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    pub message: String,
+    pub offset: usize,
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at column {}", self.message, self.offset + 1)
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+impl Expr {
+    pub fn parse(source: &str) -> Result<Self, ExprError> {
+        let mut parser = Parser { source, pos: 0 };
+        let node = parser.expr()?;
+        parser.skip_whitespace();
+        if parser.pos < source.len() {
+            return Err(parser.error("unexpected text after the expression"));
+        }
+        Ok(Expr {
+            source: source.to_string(),
+            node,
+        })
+    }
+
+    /// The columns the expression reads, in order of appearance.
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields = Vec::new();
+        self.node.collect_fields(&mut fields);
+        fields
+    }
+
+    /// Renders the expression with `field` giving the value of each column.
+    pub fn eval<'a>(&self, field: &impl Fn(&str) -> &'a str) -> String {
+        self.node.eval(field)
+    }
+}
+
+impl TryFrom<String> for Expr {
+    type Error = ExprError;
+
+    fn try_from(source: String) -> Result<Self, ExprError> {
+        Expr::parse(&source)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Node {
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a str>) {
+        match self {
+            Node::Field(name) => fields.push(name),
+            Node::Text(_) => {}
+            Node::Call(_, args) => args.iter().for_each(|a| a.collect_fields(fields)),
+            Node::Compare { lhs, rhs, .. } => {
+                lhs.collect_fields(fields);
+                rhs.collect_fields(fields);
+            }
+        }
+    }
+
+    fn eval<'a>(&self, field: &impl Fn(&str) -> &'a str) -> String {
+        match self {
+            Node::Field(name) => field(name).to_string(),
+            Node::Text(text) => text.clone(),
+            Node::Compare { equal, lhs, rhs } => {
+                let same = lhs.eval(field) == rhs.eval(field);
+                if same == *equal { "true" } else { "" }.to_string()
+            }
+            Node::Call(Func::If, args) => {
+                // Only the chosen branch is evaluated.
+                if is_true(&args[0].eval(field)) {
+                    args[1].eval(field)
+                } else {
+                    args[2].eval(field)
+                }
+            }
+            Node::Call(func, args) => {
+                let values: Vec<String> = args.iter().map(|a| a.eval(field)).collect();
+                match func {
+                    Func::Time => format_time(&values[0]),
+                    Func::Hex => format_hex(&values[0]),
+                    Func::Substr => substr(&values[0], &values[1], values.get(2)),
+                    Func::Concat => values.concat(),
+                    Func::If => unreachable!("handled above"),
+                }
+            }
+        }
+    }
+}
+
+/// Whether `if` takes its second argument, see [`Expr`]. `0` and `false`
+/// are false too, so flags extracted from JSON work as conditions.
+fn is_true(value: &str) -> bool {
+    let value = value.trim();
+    !(value.is_empty() || value == "0" || value.eq_ignore_ascii_case("false"))
+}
+
+/// Local `YYYY-MM-DD HH:MM:SS`. Integers of up to 10 digits, not counting
+/// the sign, are epoch seconds, longer ones epoch milliseconds; times without an offset are
+/// taken as local. Anything else is returned unchanged.
+fn format_time(value: &str) -> String {
+    let value = value.trim();
+    if value.is_empty() {
+        return String::new();
+    }
+    let time = if let Ok(n) = value.parse::<i64>() {
+        let millis = if value.trim_start_matches(['-', '+']).len() <= 10 {
+            n.saturating_mul(1000)
+        } else {
+            n
+        };
+        DateTime::from_timestamp_millis(millis).map(|t| t.with_timezone(&Local))
+    } else if let Ok(t) = DateTime::parse_from_rfc3339(value) {
+        Some(t.with_timezone(&Local))
+    } else {
+        ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+            .or_else(|| {
+                NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .ok()
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+            })
+            .and_then(|t| Local.from_local_datetime(&t).earliest())
+    };
+    match time {
+        Some(t) => t.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None => value.to_string(),
+    }
+}
+
+/// Integers in lowercase hexadecimal (two's complement when negative);
+/// anything else unchanged.
+fn format_hex(value: &str) -> String {
+    match value.trim().parse::<i64>() {
+        Ok(n) => format!("{:x}", n),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// Chars `[start, start + len)` of `value`, clamped to its length. Arguments
+/// that are not integers count as 0 (`start`) or the rest (`len`).
+fn substr(value: &str, start: &str, len: Option<&String>) -> String {
+    let count = value.chars().count() as i64;
+    let start = start.trim().parse::<i64>().unwrap_or(0);
+    let start = if start < 0 { count + start } else { start }.clamp(0, count);
+    let len = len
+        .and_then(|l| l.trim().parse::<i64>().ok())
+        .unwrap_or(count)
+        .max(0);
+    value
+        .chars()
+        .skip(start as usize)
+        .take(len.min(count) as usize)
+        .collect()
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: impl Into<String>) -> ExprError {
+        ExprError {
+            message: message.into(),
+            offset: self.pos,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> &'a str {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        let ident = &self.source[self.pos..self.pos + len];
+        self.pos += len;
+        ident
+    }
+
+    /// `operand (("==" | "!=") operand)?`
+    fn expr(&mut self) -> Result<Node, ExprError> {
+        let lhs = self.operand()?;
+        let equal = if self.eat("==") {
+            true
+        } else if self.eat("!=") {
+            false
+        } else {
+            return Ok(lhs);
+        };
+        let rhs = self.operand()?;
+        Ok(Node::Compare {
+            equal,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        })
+    }
+
+    fn operand(&mut self) -> Result<Node, ExprError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let Some(c) = self.rest().chars().next() else {
+            return Err(self.error("expected a field, text or function"));
+        };
+        match c {
+            '$' => {
+                self.pos += 1;
+                let name = self.ident();
+                if name.is_empty() {
+                    return Err(self.error("expected a field name after '$'"));
+                }
+                Ok(Node::Field(name.to_string()))
+            }
+            '"' | '\'' => {
+                let Some(len) = self.rest()[1..].find(c) else {
+                    return Err(self.error("unterminated text"));
+                };
+                let text = self.rest()[1..1 + len].to_string();
+                self.pos += len + 2;
+                Ok(Node::Text(text))
+            }
+            c if c.is_ascii_digit() || c == '-' => {
+                self.pos += 1;
+                self.ident();
+                let number = &self.source[start..self.pos];
+                if number.parse::<i64>().is_err() {
+                    self.pos = start;
+                    return Err(self.error(format!("invalid number '{}'", number)));
+                }
+                Ok(Node::Text(number.to_string()))
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let name = self.ident();
+                let Some(func) = Func::from_name(name) else {
+                    let message = format!(
+                        "unknown function '{}', expected time, substr, hex, concat or if (fields start with '$')",
+                        name
+                    );
+                    self.pos = start;
+                    return Err(self.error(message));
+                };
+                if !self.eat("(") {
+                    return Err(self.error(format!("expected '(' after {}", name)));
+                }
+                let mut args = Vec::new();
+                if !self.eat(")") {
+                    loop {
+                        args.push(self.expr()?);
+                        if self.eat(")") {
+                            break;
+                        }
+                        if !self.eat(",") {
+                            return Err(self.error("expected ',' or ')'"));
+                        }
+                    }
+                }
+                let (min, max) = func.arity();
+                if args.len() < min || args.len() > max {
+                    let expected = match (min, max) {
+                        (min, max) if min == max => format!("{}", min),
+                        (min, usize::MAX) => format!("at least {}", min),
+                        (min, max) => format!("{} to {}", min, max),
+                    };
+                    self.pos = start;
+                    return Err(self.error(format!(
+                        "{} takes {} arguments, got {}",
+                        name,
+                        expected,
+                        args.len()
+                    )));
+                }
+                Ok(Node::Call(func, args))
+            }
+            c => Err(self.error(format!("unexpected '{}'", c))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(source: &str, row: &[(&str, &str)]) -> String {
+        let expr = Expr::parse(source).unwrap_or_else(|e| panic!("{}: {}", source, e));
+        expr.eval(&|name| row.iter().find(|(k, _)| *k == name).map_or("", |(_, v)| *v))
+    }
+
+    fn error(source: &str) -> (String, usize) {
+        let e = Expr::parse(source).expect_err(source);
+        (e.message, e.offset)
+    }
+
+    #[test]
+    fn fields_and_literals() {
+        let row = [("level", "Warn"), ("n", "7")];
+        assert_eq!(eval("$level", &row), "Warn");
+        assert_eq!(eval("$missing", &row), "");
+        assert_eq!(eval(r#" "a'b" "#, &row), "a'b");
+        assert_eq!(eval(r#"'a"b'"#, &row), "a\"b");
+        assert_eq!(eval("-42", &row), "-42");
+        let expr = Expr::parse("concat($a, if($b == 'x', $c, $a))").unwrap();
+        assert_eq!(expr.fields(), ["a", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn comparisons_and_if() {
+        let row = [("level", "Error"), ("msg", "disk")];
+        assert_eq!(eval("$level == 'Error'", &row), "true");
+        assert_eq!(eval("$level != 'Error'", &row), "");
+        assert_eq!(eval("if($level == 'Error', '!', $msg)", &row), "!");
+        assert_eq!(eval("if($missing, '!', $msg)", &row), "disk");
+        assert_eq!(eval("if($level != $level, 1, 2)", &row), "2");
+        for flag in ["false", "FALSE", "0", " ", ""] {
+            assert_eq!(eval("if($f, 'y', 'n')", &[("f", flag)]), "n", "{:?}", flag);
+        }
+        for flag in ["true", "1", "-1", "00", "no", "x"] {
+            assert_eq!(eval("if($f, 'y', 'n')", &[("f", flag)]), "y", "{:?}", flag);
+        }
+    }
+
+    #[test]
+    fn functions() {
+        let row = [("id", "héllo wörld"), ("node", "255"), ("neg", "-1")];
+        assert_eq!(eval("hex($node)", &row), "ff");
+        assert_eq!(eval("hex($neg)", &row), "ffffffffffffffff");
+        assert_eq!(eval("hex($id)", &row), "héllo wörld");
+        assert_eq!(eval("substr($id, 0, 5)", &row), "héllo");
+        assert_eq!(eval("substr($id, -5)", &row), "wörld");
+        assert_eq!(eval("substr($id, 6, 100)", &row), "wörld");
+        assert_eq!(eval("substr($id, -100, 2)", &row), "hé");
+        assert_eq!(eval("substr($id, 'x', -1)", &row), "");
+        assert_eq!(eval("concat($node, ':', hex($node))", &row), "255:ff");
+    }
+
+    #[test]
+    fn time_formats() {
+        let expected = DateTime::from_timestamp(1_767_225_600, 0)
+            .unwrap()
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+        for value in [
+            "1767225600",
+            "1767225600000",
+            "2026-01-01T00:00:00Z",
+            "2026-01-01T01:00:00.500+01:00",
+        ] {
+            assert_eq!(eval("time($t)", &[("t", value)]), expected, "{}", value);
+        }
+        assert_eq!(
+            eval("time($t)", &[("t", "2026-01-01 08:30:00")]),
+            "2026-01-01 08:30:00"
+        );
+        assert_eq!(
+            eval("time($t)", &[("t", "2026-01-01")]),
+            "2026-01-01 00:00:00"
+        );
+        let before_epoch = |seconds| {
+            DateTime::from_timestamp(seconds, 0)
+                .unwrap()
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        };
+        assert_eq!(eval("time($t)", &[("t", "-86400")]), before_epoch(-86_400));
+        assert_eq!(
+            eval("time($t)", &[("t", "-1767225600")]),
+            before_epoch(-1_767_225_600)
+        );
+        assert_eq!(
+            eval("time($t)", &[("t", "-1767225600000")]),
+            before_epoch(-1_767_225_600)
+        );
+        assert_eq!(eval("time($t)", &[("t", "+1767225600")]), expected);
+        assert_eq!(eval("time($t)", &[("t", "soon")]), "soon");
+        assert_eq!(eval("time($t)", &[]), "");
+    }
+
+    #[test]
+    fn syntax_errors_point_at_the_problem() {
+        assert_eq!(error("").1, 0);
+        assert_eq!(error("$").1, 1);
+        assert_eq!(error("'open").1, 0);
+        assert_eq!(error("12ab"), ("invalid number '12ab'".to_string(), 0));
+        assert_eq!(error("  lower($a)").1, 2);
+        assert_eq!(error("hex $a").1, 4);
+        assert_eq!(error("concat($a $b)").1, 10);
+        assert_eq!(
+            error("x == substr($a)"),
+            ("unknown function 'x', expected time, substr, hex, concat or if (fields start with '$')".to_string(), 0)
+        );
+        assert_eq!(
+            error("'a' == substr($a)"),
+            ("substr takes 2 to 3 arguments, got 1".to_string(), 7)
+        );
+        assert_eq!(
+            error("concat()").0,
+            "concat takes at least 1 arguments, got 0"
+        );
+        assert_eq!(error("$a $b").1, 3);
+        assert_eq!(
+            Expr::parse("hex(").unwrap_err().to_string(),
+            "expected a field, text or function at column 5"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::query::{LogQuery, SortKey, SortType};
+use crate::Engine;
+use anyhow::Result;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FacetValue {
+    pub value: String,
+    pub count: u64,
+}
+
+impl Engine {
+    /// Counts the distinct values of `field` among the rows matching `query`'s
+    /// filter and full text search, most frequent first. `query`'s sort, limit
+    /// and offset are ignored; at most `limit` values are returned.
+    pub fn get_facets(&self, field: &str, query: &LogQuery, limit: u32) -> Result<Vec<FacetValue>> {
+        self.check_column(field)?;
+        // An ascending index on the field turns the GROUP BY into an index scan.
+        self.ensure_sort_index(&[SortKey {
+            column: field.to_string(),
+            descending: false,
+            sort_type: SortType::Text,
+        }])?;
+
+        // No alias for the count: any name could also be a configured column.
+        let sql = format!(
+            "SELECT {field}, COUNT(*) FROM logs {} GROUP BY {field} ORDER BY COUNT(*) DESC, {field} LIMIT ?1",
+            query.where_clause()?
+        );
+        let mut stmt = self.db.conn.prepare(&sql)?;
+        let rows = stmt.query_map([limit], |row| {
+            Ok(FacetValue {
+                value: row.get::<_, Option<String>>(0)?.unwrap_or_default(),
+                count: row.get::<_, i64>(1)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+node = "$line.node"
+"#;
+
+    fn engine() -> Engine {
+        test_util::engine(
+            RULES,
+            &[
+                r#"{"level":"Warn","node":"a"}"#,
+                r#"{"level":"Error","node":"a"}"#,
+                r#"{"level":"Info","node":"b"}"#,
+                r#"{"level":"Error","node":"b"}"#,
+                r#"{"level":"Warn","node":"a"}"#,
+                r#"{"level":"Info","node":"a"}"#,
+                r#"{"level":"Debug"}"#,
+                r#"{"level":"Error","node":""}"#,
+            ],
+        )
+    }
+
+    fn facets(engine: &Engine, field: &str, query: &LogQuery, limit: u32) -> Vec<(String, u64)> {
+        engine
+            .get_facets(field, query, limit)
+            .unwrap()
+            .into_iter()
+            .map(|f| (f.value, f.count))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, u64)]) -> Vec<(String, u64)> {
+        expected.iter().map(|&(v, c)| (v.to_string(), c)).collect()
+    }
+
+    #[test]
+    fn most_frequent_first_then_by_value() {
+        let engine = engine();
+        let all = LogQuery::default();
+        assert_eq!(
+            facets(&engine, "level", &all, 10),
+            pairs(&[("Error", 3), ("Info", 2), ("Warn", 2), ("Debug", 1)])
+        );
+        assert_eq!(
+            facets(&engine, "level", &all, 2),
+            pairs(&[("Error", 3), ("Info", 2)])
+        );
+    }
+
+    #[test]
+    fn missing_and_empty_values_count_as_empty() {
+        let engine = engine();
+        assert_eq!(
+            facets(&engine, "node", &LogQuery::default(), 10),
+            pairs(&[("a", 4), ("", 2), ("b", 2)])
+        );
+    }
+
+    #[test]
+    fn counts_only_matching_rows() {
+        let engine = engine();
+        let query = LogQuery {
+            filter_sql: "node = 'a'".to_string(),
+            fts_query: "rr".to_string(),
+            sort: vec![SortKey {
+                column: "level".to_string(),
+                descending: true,
+                sort_type: SortType::Text,
+            }],
+            limit: 1,
+            offset: 5,
+            ..Default::default()
+        };
+        assert_eq!(facets(&engine, "level", &query, 10), pairs(&[("Error", 1)]));
+    }
+
+    #[test]
+    fn rejects_unknown_columns() {
+        let engine = engine();
+        for field in ["nope", "raw", "level FROM logs --"] {
+            let error = engine
+                .get_facets(field, &LogQuery::default(), 10)
+                .unwrap_err();
+            assert_eq!(error.to_string(), format!("Unknown column '{}'", field));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::query::LogQuery;
+use crate::Engine;
+use anyhow::Result;
+
+const SECOND: i64 = 1000;
+const MINUTE: i64 = 60 * SECOND;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// Most buckets a histogram is split into, whatever the caller asks for, so
+/// the counts fit in memory for any time span.
+const MAX_BUCKETS: u32 = 10_000;
+
+/// Bucket widths a timeline axis can label sensibly, smallest first.
+const NICE_WIDTHS: &[i64] = &[
+    1,
+    2,
+    5,
+    10,
+    20,
+    50,
+    100,
+    200,
+    500,
+    SECOND,
+    2 * SECOND,
+    5 * SECOND,
+    10 * SECOND,
+    15 * SECOND,
+    30 * SECOND,
+    MINUTE,
+    2 * MINUTE,
+    5 * MINUTE,
+    10 * MINUTE,
+    15 * MINUTE,
+    30 * MINUTE,
+    HOUR,
+    2 * HOUR,
+    3 * HOUR,
+    6 * HOUR,
+    12 * HOUR,
+    DAY,
+    2 * DAY,
+    7 * DAY,
+    30 * DAY,
+    365 * DAY,
+];
+
+/// Row counts per time bucket. Bucket `i` covers
+/// `[start_ms + i * bucket_ms, start_ms + (i + 1) * bucket_ms)` on the `ts` column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Histogram {
+    pub start_ms: i64,
+    pub bucket_ms: i64,
+    pub counts: Vec<u64>,
+}
+
+impl Engine {
+    /// Buckets the rows matching `query`'s filter and full text search by event
+    /// time. The bucket width is the smallest "nice" width that splits the
+    /// matching time span into about `buckets` buckets, at most
+    /// [`MAX_BUCKETS`]. Rows whose time could not be parsed are not counted.
+    pub fn get_histogram(&self, query: &LogQuery, buckets: u32) -> Result<Histogram> {
+        if self.db.time_field.is_none() {
+            anyhow::bail!("No time field configured, add a [time] section to the rules");
+        }
+        self.db.create_index("logs_ts", "ts")?;
+
+        let where_str = match query.where_clause()? {
+            w if w.is_empty() => "WHERE ts IS NOT NULL".to_string(),
+            w => format!("{} AND ts IS NOT NULL", w),
+        };
+        let span_sql = format!("SELECT MIN(ts), MAX(ts) FROM logs {}", where_str);
+        let (min, max): (Option<i64>, Option<i64>) =
+            self.db
+                .conn
+                .query_row(&span_sql, [], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        let (Some(min), Some(max)) = (min, max) else {
+            return Ok(Histogram::default());
+        };
+
+        let bucket_ms = bucket_width(max - min, buckets.clamp(1, MAX_BUCKETS));
+        let start_ms = min.div_euclid(bucket_ms) * bucket_ms;
+        let len = ((max - start_ms) / bucket_ms + 1) as usize;
+        let mut counts = vec![0u64; len];
+
+        let sql = format!(
+            "SELECT (ts - ?1) / ?2 AS bucket, COUNT(*) FROM logs {} GROUP BY bucket",
+            where_str
+        );
+        let mut stmt = self.db.conn.prepare(&sql)?;
+        let mut rows = stmt.query([start_ms, bucket_ms])?;
+        while let Some(row) = rows.next()? {
+            let bucket: i64 = row.get(0)?;
+            let count: i64 = row.get(1)?;
+            if let Some(slot) = counts.get_mut(bucket as usize) {
+                *slot = count as u64;
+            }
+        }
+
+        Ok(Histogram {
+            start_ms,
+            bucket_ms,
+            counts,
+        })
+    }
+}
+
+fn bucket_width(span_ms: i64, buckets: u32) -> i64 {
+    let min_width = span_ms / i64::from(buckets) + 1;
+    let year = 365 * DAY;
+    NICE_WIDTHS
+        .iter()
+        .copied()
+        .find(|w| *w >= min_width)
+        .unwrap_or((min_width + year - 1) / year * year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    const RULES: &str = r#"
+[logs]
+t = "$line.t"
+
+[time]
+field = "t"
+"#;
+
+    #[test]
+    fn bucket_width_is_the_smallest_nice_width_covering_the_span() {
+        assert_eq!(bucket_width(0, 1), 1);
+        assert_eq!(bucket_width(999, 1000), 1);
+        // Just over 30 one-second buckets needs the next nice width.
+        assert_eq!(bucket_width(30 * SECOND, 30), 2 * SECOND);
+        assert_eq!(bucket_width(HOUR, 60), 2 * MINUTE);
+    }
+
+    #[test]
+    fn bucket_width_rounds_past_the_table_to_whole_years() {
+        let year = 365 * DAY;
+        assert_eq!(bucket_width(10 * year, 1), 11 * year);
+        assert_eq!(bucket_width(i64::MAX / 2, 1) % year, 0);
+    }
+
+    #[test]
+    fn bucket_count_is_capped() {
+        let engine = test_util::engine(
+            RULES,
+            &[
+                r#"{"t": "2020-01-01T00:00:00Z"}"#,
+                r#"{"t": "2021-01-01T00:00:00Z"}"#,
+            ],
+        );
+        let histogram = engine
+            .get_histogram(&LogQuery::default(), u32::MAX)
+            .unwrap();
+        assert!(histogram.counts.len() <= MAX_BUCKETS as usize + 1);
+        assert_eq!(histogram.counts.iter().sum::<u64>(), 2);
+    }
+
+    #[test]
+    fn rows_without_a_time_are_not_counted() {
+        let engine = test_util::engine(
+            RULES,
+            &[
+                r#"{"t": "2020-01-01T00:00:00Z"}"#,
+                r#"{"t": "yesterday"}"#,
+                r#"{"t": "2020-01-01T00:00:01.500Z"}"#,
+            ],
+        );
+        let histogram = engine.get_histogram(&LogQuery::default(), 3).unwrap();
+        assert_eq!(histogram.bucket_ms, 1000);
+        assert_eq!(histogram.counts, vec![1, 1]);
+    }
+}
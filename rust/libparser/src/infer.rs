@@ -0,0 +1,386 @@
+use crate::validate::check_name;
+use chrono::{DateTime, NaiveDateTime};
+use rusqlite::Connection;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// The column every suggestion maps to `$lineno`; the app shows it as the
+/// line number.
+const LINE_NUMBER: &str = "lineNumber";
+/// Cells of the suggested `[[col]]` layout besides the time.
+const LAYOUT_FIELDS: usize = 3;
+/// Distinct values counted per field, to tell tag-like fields apart.
+const MAX_DISTINCT: usize = 8;
+
+/// The JSON type of the values seen at a path. Null values count as missing
+/// and do not change the kind; integers and floats together are `Float`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Only `null` was seen.
+    Null,
+    Bool,
+    Integer,
+    Float,
+    String,
+    Array,
+    /// More than one of the above.
+    Mixed,
+}
+
+impl ValueKind {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => ValueKind::Null,
+            Value::Bool(_) => ValueKind::Bool,
+            Value::Number(n) if n.is_f64() => ValueKind::Float,
+            Value::Number(_) => ValueKind::Integer,
+            Value::String(_) => ValueKind::String,
+            Value::Array(_) => ValueKind::Array,
+            // Objects are descended into.
+            Value::Object(_) => ValueKind::Mixed,
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        use ValueKind::*;
+        match (self, other) {
+            (Null, kind) | (kind, Null) => kind,
+            (a, b) if a == b => a,
+            (Integer, Float) | (Float, Integer) => Float,
+            _ => Mixed,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ValueKind::Null => "null",
+            ValueKind::Bool => "bool",
+            ValueKind::Integer => "integer",
+            ValueKind::Float => "float",
+            ValueKind::String => "string",
+            ValueKind::Array => "array",
+            ValueKind::Mixed => "mixed",
+        }
+    }
+}
+
+/// A JSON key path seen in the sample, with the column suggested for it.
+#[derive(Debug, Clone)]
+pub struct InferredField {
+    /// A column name that is a valid, unused SQL identifier: the last key,
+    /// prefixed with its parents when that name is taken.
+    pub name: String,
+    /// The `[logs]` path, e.g. `$line.Event.eventName`.
+    pub path: String,
+    pub kind: ValueKind,
+    /// Share of the JSON lines where the path holds a value that is not null.
+    pub fill_rate: f64,
+    /// The values repeat among a few distinct ones, like a level or an
+    /// event name; the suggested layout shows such fields as tags.
+    pub repeated: bool,
+}
+
+/// The fields of a log format, inferred from sample lines by
+/// [`infer_schema`].
+#[derive(Debug, Clone)]
+pub struct InferredSchema {
+    /// Sample lines that were not blank.
+    pub sampled_lines: usize,
+    /// Sample lines that were JSON objects; the others are only in `raw`.
+    pub json_lines: usize,
+    /// Every path to a value that is not an object, in order of first
+    /// appearance, the keys of each object taken in sorted order. Keys that
+    /// are empty or contain `.` cannot be written as a path and are left out.
+    pub fields: Vec<InferredField>,
+    /// The field best suited for `[time]`: the fullest one whose values are
+    /// all ISO-8601 times.
+    pub time_field: Option<String>,
+}
+
+impl InferredSchema {
+    /// A rule file mapping every field, plus `lineNumber` for the line
+    /// number, with `[time]` when a time field was found and a `[[col]]`
+    /// layout of the time and the fullest few fields. Each field is commented
+    /// with its kind and fill rate, to help trimming the list.
+    pub fn to_rules(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail.
+        let _ = self.write_rules(&mut out);
+        out
+    }
+
+    fn write_rules(&self, out: &mut String) -> std::fmt::Result {
+        writeln!(
+            out,
+            "# Generated from {} sample line(s), {} of them JSON objects.",
+            self.sampled_lines, self.json_lines
+        )?;
+        writeln!(
+            out,
+            "# Check the names and drop the fields you do not need."
+        )?;
+        writeln!(out, "[logs]")?;
+        let entries: Vec<(String, String)> = std::iter::once((
+            format!("{} = {}", LINE_NUMBER, quote("$lineno")),
+            "line number".to_string(),
+        ))
+        .chain(self.fields.iter().map(|f| {
+            (
+                format!("{} = {}", f.name, quote(&f.path)),
+                format!("{}, {:.0}% filled", f.kind.name(), f.fill_rate * 100.0),
+            )
+        }))
+        .collect();
+        let width = entries.iter().map(|(e, _)| e.len()).max().unwrap_or(0);
+        for (entry, comment) in &entries {
+            writeln!(out, "{:width$}  # {}", entry, comment)?;
+        }
+
+        if let Some(field) = &self.time_field {
+            writeln!(out, "\n[time]\nfield = {}", quote(field))?;
+            writeln!(out, "\n[[col]]\nwidth = 160")?;
+            writeln!(out, "expr = {}", quote(&format!("time(${})", field)))?;
+            writeln!(out, "style = \"meta\"")?;
+        }
+        let mut shown: Vec<&InferredField> = self
+            .fields
+            .iter()
+            .filter(|f| Some(&f.name) != self.time_field.as_ref())
+            .filter(|f| {
+                matches!(
+                    f.kind,
+                    ValueKind::String | ValueKind::Integer | ValueKind::Float | ValueKind::Bool
+                )
+            })
+            .collect();
+        // Stable, so equally full fields keep their order in the logs.
+        shown.sort_by(|a, b| b.fill_rate.total_cmp(&a.fill_rate));
+        for field in shown.into_iter().take(LAYOUT_FIELDS) {
+            let style = if field.repeated { "tag" } else { "text" };
+            writeln!(out, "\n[[col]]\nflex = 1")?;
+            writeln!(out, "expr = {}", quote(&format!("${}", field.name)))?;
+            writeln!(out, "style = {}", quote(style))?;
+        }
+        Ok(())
+    }
+}
+
+/// `value` as a TOML basic string.
+fn quote(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+/// What was seen at one path.
+struct PathStats {
+    keys: Vec<String>,
+    kind: ValueKind,
+    filled: usize,
+    /// Every string value so far was an ISO-8601 time.
+    times: bool,
+    distinct: HashSet<String>,
+}
+
+/// Infers the fields of the logs in `lines`, usually the first lines of a
+/// file as [`crate::read_sample`] reads them.
+pub fn infer_schema(lines: &[impl AsRef<str>]) -> InferredSchema {
+    let mut paths: Vec<PathStats> = Vec::new();
+    let mut index: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut sampled_lines = 0;
+    let mut json_lines = 0;
+    for line in lines {
+        let line = line.as_ref();
+        if line.trim().is_empty() {
+            continue;
+        }
+        sampled_lines += 1;
+        if let Ok(Value::Object(root)) = serde_json::from_str::<Value>(line) {
+            json_lines += 1;
+            collect(&root, &mut Vec::new(), &mut paths, &mut index);
+        }
+    }
+
+    let sql = Connection::open_in_memory().ok();
+    let mut taken = vec![LINE_NUMBER.to_lowercase()];
+    let fields: Vec<InferredField> = paths
+        .iter()
+        .map(|stats| {
+            let name = column_name(&stats.keys, &taken, sql.as_ref());
+            taken.push(name.to_lowercase());
+            InferredField {
+                name,
+                path: format!("$line.{}", stats.keys.join(".")),
+                kind: stats.kind,
+                fill_rate: if json_lines == 0 {
+                    0.0
+                } else {
+                    stats.filled as f64 / json_lines as f64
+                },
+                repeated: stats.distinct.len() <= MAX_DISTINCT
+                    && stats.distinct.len() < stats.filled,
+            }
+        })
+        .collect();
+
+    let time_field = paths
+        .iter()
+        .zip(&fields)
+        .filter(|(stats, _)| stats.kind == ValueKind::String && stats.times)
+        // `max_by_key` keeps the last maximum, so reverse to prefer the first.
+        .rev()
+        .max_by_key(|(stats, _)| stats.filled)
+        .map(|(_, field)| field.name.clone());
+
+    InferredSchema {
+        sampled_lines,
+        json_lines,
+        fields,
+        time_field,
+    }
+}
+
+/// Adds the leaves of `object`, whose keys from the root are `keys`.
+fn collect(
+    object: &Map<String, Value>,
+    keys: &mut Vec<String>,
+    paths: &mut Vec<PathStats>,
+    index: &mut HashMap<Vec<String>, usize>,
+) {
+    for (key, value) in object {
+        if key.is_empty() || key.contains('.') {
+            continue;
+        }
+        keys.push(key.clone());
+        match value {
+            Value::Object(child) if !child.is_empty() => collect(child, keys, paths, index),
+            Value::Object(_) => {}
+            value => {
+                let i = *index.entry(keys.clone()).or_insert_with(|| {
+                    paths.push(PathStats {
+                        keys: keys.clone(),
+                        kind: ValueKind::Null,
+                        filled: 0,
+                        times: true,
+                        distinct: HashSet::new(),
+                    });
+                    paths.len() - 1
+                });
+                let stats = &mut paths[i];
+                stats.kind = stats.kind.merge(ValueKind::of(value));
+                if !value.is_null() {
+                    stats.filled += 1;
+                    let text = match value {
+                        Value::String(s) => s.clone(),
+                        v => v.to_string(),
+                    };
+                    if value.is_string() && !is_time(&text) {
+                        stats.times = false;
+                    }
+                    if stats.distinct.len() <= MAX_DISTINCT {
+                        stats.distinct.insert(text);
+                    }
+                }
+            }
+        }
+        keys.pop();
+    }
+}
+
+/// Whether SQLite's `julianday`, which fills `ts`, understands `value`.
+fn is_time(value: &str) -> bool {
+    DateTime::parse_from_rfc3339(value).is_ok()
+        || ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+            .iter()
+            .any(|f| NaiveDateTime::parse_from_str(value, f).is_ok())
+}
+
+/// The last of `keys` as an identifier, prefixed with parent keys until it
+/// is a usable name not in `taken` (lowercase), numbered as a last resort.
+fn column_name(keys: &[String], taken: &[String], sql: Option<&Connection>) -> String {
+    let usable =
+        |name: &str| check_name(name, sql).is_none() && !taken.contains(&name.to_lowercase());
+    for start in (0..keys.len()).rev() {
+        let name = identifier(&keys[start..].join("_"));
+        if usable(&name) {
+            return name;
+        }
+    }
+    // An identifier with a numeric suffix is never a keyword or reserved,
+    // so this ends once `n` is past the names in `taken`.
+    let base = identifier(&keys.join("_"));
+    let mut n = 2;
+    loop {
+        let name = format!("{}_{}", base, n);
+        if usable(&name) {
+            return name;
+        }
+        n += 1;
+    }
+}
+
+/// `key` with every char that is not an ASCII letter, digit or `_` replaced
+/// by `_`, and `_` in front of a leading digit.
+fn identifier(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name(keys: &[&str], taken: &[&str]) -> String {
+        let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let taken: Vec<String> = taken.iter().map(|t| t.to_lowercase()).collect();
+        let sql = Connection::open_in_memory().unwrap();
+        column_name(&keys, &taken, Some(&sql))
+    }
+
+    #[test]
+    fn last_key_then_parents() {
+        assert_eq!(name(&["Event", "name"], &[]), "name");
+        assert_eq!(name(&["Event", "name"], &["name"]), "Event_name");
+        assert_eq!(name(&["a", "b", "c"], &["c", "b_c"]), "a_b_c");
+        assert_eq!(name(&["a", "b"], &["b", "a_b"]), "a_b_2");
+        assert_eq!(name(&["a", "b"], &["b", "a_b", "a_b_2"]), "a_b_3");
+    }
+
+    #[test]
+    fn reserved_and_keywords_are_numbered() {
+        assert_eq!(name(&["id"], &[]), "id_2");
+        assert_eq!(name(&["Raw"], &[]), "Raw_2");
+        assert_eq!(name(&["select"], &[]), "select_2");
+        assert_eq!(name(&["req", "select"], &[]), "req_select");
+    }
+
+    #[test]
+    fn taken_names_ignore_case() {
+        assert_eq!(name(&["T"], &["t"]), "T_2");
+        assert_eq!(name(&["LineNumber"], &[LINE_NUMBER]), "LineNumber_2");
+    }
+
+    #[test]
+    fn keys_become_identifiers() {
+        assert_eq!(name(&["1st key"], &[]), "_1st_key");
+        assert_eq!(name(&["user-agent"], &[]), "user_agent");
+        assert_eq!(name(&["größe"], &[]), "gr__e");
+    }
+
+    #[test]
+    fn schema_names_are_unique() {
+        let lines = [r#"{"id": 1, "a": {"t": 1}, "b": {"t": 2}, "T": 3}"#];
+        let names: Vec<String> = infer_schema(&lines)
+            .fields
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(names, ["T", "a_t", "b_t", "id_2"]);
+    }
+}
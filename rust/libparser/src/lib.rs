@@ -1,29 +1,112 @@
 use anyhow::{Context, Result};
-use serde_json::Value;
-use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufReader;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 pub mod config;
 pub mod db;
+pub mod export;
+pub mod expr;
+pub mod facet;
+pub mod histogram;
+pub mod infer;
+mod loader;
 pub mod parser;
+pub mod query;
+mod render;
+pub mod sample;
+pub mod search;
+#[cfg(test)]
+mod test_util;
+pub mod validate;
 
-pub use config::Config;
-use db::Db;
+pub use config::{CellConfig, CellStyle, ColumnConfig, Config, TimeConfig, ViewConfig};
+use db::{Db, RawSource};
+pub use export::ExportFormat;
+pub use expr::{Expr, ExprError};
+pub use facet::FacetValue;
+pub use histogram::Histogram;
+pub use infer::{infer_schema, InferredField, InferredSchema, ValueKind};
+pub use query::{LogPage, LogQuery, LogRecord, SortKey, SortType};
+pub use sample::{read_sample, DryRun, DryRunRow};
+pub use search::{MatchSpan, SearchMode, SearchOptions, SearchStrategy};
+pub use validate::Diagnostic;
+
+/// How `load_file` turns the bytes of a line into the `raw` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecodeMode {
+    /// Invalid UTF-8 is replaced with U+FFFD and a trailing `\r` is dropped,
+    /// so `raw` is always clean text.
+    #[default]
+    Lossy,
+    /// `raw` keeps the exact bytes of the line without its `\n`, as TEXT when
+    /// they are valid UTF-8 and as a BLOB otherwise. Fields are extracted from
+    /// the lossy text either way.
+    Raw,
+}
+
+/// Where `load_file` keeps the `raw` line of each row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RawStorage {
+    /// `raw` is stored in the database.
+    #[default]
+    Inline,
+    /// Only the byte offset and length of each line are stored, in
+    /// `raw_offset` and `raw_len`. `raw` stays queryable as a virtual column
+    /// that reads the line from a memory map of the file, so the file must
+    /// not change while the engine is alive: if it is truncated, reading
+    /// `raw` kills the process with SIGBUS. Only one file can be loaded this
+    /// way, into an empty engine, and nothing can be loaded after it.
+    Offsets,
+}
+
+/// How `load_file` reads the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReaderBackend {
+    /// Lines are copied one by one out of a `BufReader`.
+    #[default]
+    Buffered,
+    /// The file is memory-mapped and split into lines in place. The process
+    /// is killed with SIGBUS if the file is truncated while it is loaded.
+    Mmap,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LoadOptions {
+    pub decode: DecodeMode,
+    pub storage: RawStorage,
+    pub reader: ReaderBackend,
+}
 
 pub struct LoadStats {
     pub inserted_lines: usize,
+    /// Lines that were not valid UTF-8. They are still loaded.
+    pub malformed_lines: usize,
     pub total_duration: Duration,
     pub read_duration: Duration,
     pub db_duration: Duration,
     pub fts_duration: Duration,
+    /// Time spent extracting the configured fields, part of `read_duration`.
+    pub parse_duration: Duration,
+    /// Roughly how long parsing every line into a full `serde_json::Value`
+    /// and reading the fields from it would have taken instead, extrapolated
+    /// from a sample of lines.
+    pub full_parse_estimate: Duration,
+}
+
+impl LoadStats {
+    /// Parse time saved by extracting only the configured fields.
+    pub fn parse_savings(&self) -> Duration {
+        self.full_parse_estimate.saturating_sub(self.parse_duration)
+    }
 }
 
 pub struct QueryResult {
     pub headers: Vec<String>,
-    pub rows: Vec<Vec<String>>,
+    /// Every value as text, `None` for NULL.
+    pub rows: Vec<Vec<Option<String>>>,
     pub duration: Duration,
 }
 
@@ -39,44 +122,132 @@ impl Engine {
     }
 
     pub fn new(config: Config) -> Result<Self> {
-        let db = Db::new(&config).context("Failed to initialize DB")?;
+        let db = Db::new(&config, None).context("Failed to initialize DB")?;
         Ok(Self { config, db })
     }
 
+    /// An engine over the same rows on its own read-only connection, for
+    /// running a long query such as an export on another thread while this
+    /// engine keeps serving. It sees later loads into this engine only once
+    /// they commit, and creates no sort indexes: it sorts without one unless
+    /// this engine already made it.
+    pub fn reader(&self) -> Result<Self> {
+        let db = self.db.reader().context("Failed to open a reader")?;
+        Ok(Self {
+            config: self.config.clone(),
+            db,
+        })
+    }
+
+    /// Restricts SQL on this engine to reading, for serving untrusted
+    /// queries: statements that change rows or the schema are rejected, other
+    /// than the indexes the engine creates on demand, and ATTACH is disabled.
+    /// Filters are SQL too, so this covers `filter_sql` as well.
+    pub fn restrict_to_reads(&self) {
+        self.db.restrict_to_reads();
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     pub fn columns(&self) -> Vec<String> {
         let mut all_columns = Vec::with_capacity(self.db.columns.len() + 2);
         all_columns.push("id".to_string());
         all_columns.push("raw".to_string());
         all_columns.extend(self.db.columns.clone());
+        if self.db.time_field.is_some() {
+            all_columns.push("ts".to_string());
+        }
         all_columns
     }
 
     pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<LoadStats> {
+        self.load_file_with(path, &LoadOptions::default())
+    }
+
+    pub fn load_file_with<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        options: &LoadOptions,
+    ) -> Result<LoadStats> {
+        if self.db.offsets && options.storage != RawStorage::Offsets {
+            anyhow::bail!("The engine uses offset storage, load with the same options");
+        }
         let file = File::open(&path)
             .with_context(|| format!("Failed to open file {:?}", path.as_ref()))?;
-        let reader = BufReader::new(file);
-        self.load_reader(reader)
+        if options.storage == RawStorage::Inline && options.reader == ReaderBackend::Buffered {
+            return self.load_reader(BufReader::new(file), options);
+        }
+
+        // SAFETY: the mapping is only read, but nothing stops another process
+        // from changing the file while it is loaded (or, for
+        // `RawStorage::Offsets`, while the engine is alive). Rewritten bytes
+        // show up in `raw`; if the file is truncated, touching the pages past
+        // its new end raises SIGBUS and kills the process. Both options that
+        // map the file are therefore opt-in, for files nothing writes to any
+        // more, like rotated logs.
+        let map = Arc::new(
+            unsafe { memmap2::Mmap::map(&file) }
+                .with_context(|| format!("Failed to map file {:?}", path.as_ref()))?,
+        );
+        if options.storage == RawStorage::Offsets {
+            self.use_offset_storage(map.clone(), options.decode)?;
+        }
+        match options.reader {
+            ReaderBackend::Buffered => self.load_reader(&map[..], options),
+            ReaderBackend::Mmap => self.load_mapped(&map, options),
+        }
+    }
+
+    /// Recreates the empty database so that `raw` reads from `map`.
+    fn use_offset_storage(&mut self, map: Arc<memmap2::Mmap>, decode: DecodeMode) -> Result<()> {
+        let loaded: bool =
+            self.db
+                .conn
+                .query_row("SELECT EXISTS(SELECT 1 FROM logs)", [], |row| row.get(0))?;
+        if loaded {
+            anyhow::bail!("Offset storage needs an engine with no rows loaded");
+        }
+        let source = RawSource { map, decode };
+        self.db = Db::new(&self.config, Some(source)).context("Failed to initialize DB")?;
+        Ok(())
+    }
+
+    /// The `raw` bytes of row `id` exactly as stored, see [`DecodeMode`].
+    pub fn get_raw(&self, id: i64) -> Result<Option<Vec<u8>>> {
+        let mut stmt = self.db.conn.prepare("SELECT raw FROM logs WHERE id = ?1")?;
+        let mut rows = stmt.query([id])?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+        Ok(match row.get_ref(0)? {
+            rusqlite::types::ValueRef::Text(bytes) | rusqlite::types::ValueRef::Blob(bytes) => {
+                Some(bytes.to_vec())
+            }
+            _ => Some(Vec::new()),
+        })
     }
 
     pub fn execute_query(&self, query: &str) -> Result<QueryResult> {
+        self.execute_query_with(query, [])
+    }
+
+    pub(crate) fn execute_query_with<P: rusqlite::Params>(
+        &self,
+        query: &str,
+        params: P,
+    ) -> Result<QueryResult> {
         let start = Instant::now();
         let mut stmt = self.db.conn.prepare(query)?;
         let headers: Vec<String> = stmt.column_names().iter().map(|s| s.to_string()).collect();
-        let mut rows_iter = stmt.query([])?;
+        let mut rows_iter = stmt.query(params)?;
         let mut rows = Vec::new();
 
         while let Some(row) = rows_iter.next()? {
             let mut values = Vec::with_capacity(headers.len());
             for i in 0..headers.len() {
-                let value = row.get_ref(i)?;
-                let val_str = match value {
-                    rusqlite::types::ValueRef::Null => "NULL".to_string(),
-                    rusqlite::types::ValueRef::Integer(i) => i.to_string(),
-                    rusqlite::types::ValueRef::Real(f) => f.to_string(),
-                    rusqlite::types::ValueRef::Text(t) => String::from_utf8_lossy(t).to_string(),
-                    rusqlite::types::ValueRef::Blob(_) => "[BLOB]".to_string(),
-                };
-                values.push(val_str);
+                values.push(db::value_text(row.get_ref(i)?));
             }
             rows.push(values);
         }
@@ -87,98 +258,4 @@ impl Engine {
             duration: start.elapsed(),
         })
     }
-
-    fn load_reader<R: BufRead>(&mut self, reader: R) -> Result<LoadStats> {
-        let start_total = Instant::now();
-        let mut start_chunk = Instant::now();
-        let mut buffer: Vec<(String, HashMap<String, String>)> = Vec::new();
-        let mut inserted_lines = 0;
-        let mut total_db_duration = Duration::new(0, 0);
-
-        for (idx, line) in reader.lines().enumerate() {
-            let line = line?;
-            let line_no = idx + 1;
-            if line.trim().is_empty() {
-                continue;
-            }
-            let json_value = Self::parse_json_line(&line);
-            let row = self.build_row(&json_value, &line, line_no);
-            buffer.push((line, row));
-            if buffer.len() >= 1000 {
-                self.flush_batch(
-                    &mut buffer,
-                    &mut total_db_duration,
-                    &mut inserted_lines,
-                    &mut start_chunk,
-                )?;
-            }
-        }
-
-        if !buffer.is_empty() {
-            self.flush_batch(
-                &mut buffer,
-                &mut total_db_duration,
-                &mut inserted_lines,
-                &mut start_chunk,
-            )?;
-        }
-
-        let fts_start = Instant::now();
-        self.db.rebuild_fts()?;
-        let fts_duration = fts_start.elapsed();
-        total_db_duration += fts_duration;
-
-        let total_duration = start_total.elapsed();
-        let read_duration = total_duration.saturating_sub(total_db_duration);
-
-        Ok(LoadStats {
-            inserted_lines,
-            total_duration,
-            read_duration,
-            fts_duration,
-            db_duration: total_db_duration,
-        })
-    }
-
-    fn parse_json_line(line: &str) -> Value {
-        match serde_json::from_str(line) {
-            Ok(value) => value,
-            Err(_) => Value::Null,
-        }
-    }
-
-    fn build_row(&self, json_value: &Value, line: &str, line_no: usize) -> HashMap<String, String> {
-        let mut row = HashMap::new();
-        for (col, path) in &self.config.logs {
-            let val = parser::extract_field(json_value, path, line, line_no);
-            row.insert(col.clone(), val);
-        }
-        row
-    }
-
-    fn flush_batch(
-        &mut self,
-        buffer: &mut Vec<(String, HashMap<String, String>)>,
-        total_db_duration: &mut Duration,
-        inserted_lines: &mut usize,
-        start_chunk: &mut Instant,
-    ) -> Result<()> {
-        let db_start = Instant::now();
-        self.db.insert_batch(buffer)?;
-        let db_duration = db_start.elapsed();
-        *total_db_duration += db_duration;
-        *inserted_lines += buffer.len();
-        buffer.clear();
-        let total_duration = start_chunk.elapsed();
-        let read_duration = total_duration.saturating_sub(db_duration);
-        log::info!(
-            "Loaded {} lines. Read {:?}, SQLite {:?}, Total {:?}",
-            inserted_lines,
-            read_duration,
-            db_duration,
-            total_duration
-        );
-        *start_chunk = Instant::now();
-        Ok(())
-    }
 }
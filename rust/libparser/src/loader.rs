@@ -0,0 +1,438 @@
+use crate::db::RawValue;
+use crate::parser::{extract_field, FieldExtractor};
+use crate::{DecodeMode, Engine, LoadOptions, LoadStats, RawStorage};
+use anyhow::Result;
+use rusqlite::types::Value as SqlValue;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+/// Rows inserted per transaction.
+const BATCH_SIZE: usize = 1000;
+/// One in this many lines is also parsed into a full [`Value`] and read with
+/// [`extract_field`], to estimate what [`FieldExtractor`] saves.
+const FULL_PARSE_SAMPLE: usize = 100;
+
+impl Engine {
+    /// Feeds every line of `reader` to a [`Loader`], copying each into a
+    /// reusable buffer.
+    pub(crate) fn load_reader<R: BufRead>(
+        &mut self,
+        mut reader: R,
+        options: &LoadOptions,
+    ) -> Result<LoadStats> {
+        let mut loader = Loader::new(self, options);
+        let mut bytes = Vec::new();
+        let mut offset = 0;
+        loop {
+            bytes.clear();
+            let read = reader.read_until(b'\n', &mut bytes)?;
+            if read == 0 {
+                break;
+            }
+            if bytes.last() == Some(&b'\n') {
+                bytes.pop();
+            }
+            loader.push(offset, &bytes)?;
+            offset += read;
+        }
+        loader.finish()
+    }
+
+    /// Feeds every line of a mapped file to a [`Loader`] as a slice of the
+    /// mapping, without copying.
+    pub(crate) fn load_mapped(&mut self, map: &[u8], options: &LoadOptions) -> Result<LoadStats> {
+        let mut loader = Loader::new(self, options);
+        let mut offset = 0;
+        for end in memchr::memchr_iter(b'\n', map) {
+            loader.push(offset, &map[offset..end])?;
+            offset = end + 1;
+        }
+        if offset < map.len() {
+            loader.push(offset, &map[offset..])?;
+        }
+        loader.finish()
+    }
+}
+
+/// Turns lines into rows and inserts them in batches of [`BATCH_SIZE`].
+struct Loader<'a> {
+    engine: &'a mut Engine,
+    options: &'a LoadOptions,
+    extractor: FieldExtractor,
+    buffer: Vec<(RawValue, HashMap<String, String>)>,
+    line_no: usize,
+    parsed_lines: usize,
+    inserted_lines: usize,
+    malformed_lines: usize,
+    db_duration: Duration,
+    parse_duration: Duration,
+    sampled_lines: usize,
+    sampled_duration: Duration,
+    start_total: Instant,
+    start_chunk: Instant,
+}
+
+impl<'a> Loader<'a> {
+    fn new(engine: &'a mut Engine, options: &'a LoadOptions) -> Self {
+        let extractor = FieldExtractor::new(&engine.config.logs);
+        Loader {
+            engine,
+            options,
+            extractor,
+            buffer: Vec::with_capacity(BATCH_SIZE),
+            line_no: 0,
+            parsed_lines: 0,
+            inserted_lines: 0,
+            malformed_lines: 0,
+            db_duration: Duration::ZERO,
+            parse_duration: Duration::ZERO,
+            sampled_lines: 0,
+            sampled_duration: Duration::ZERO,
+            start_total: Instant::now(),
+            start_chunk: Instant::now(),
+        }
+    }
+
+    /// `bytes` is the line starting at byte `offset` of the input, without
+    /// its `\n`. Blank lines are counted but not loaded.
+    fn push(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        self.line_no += 1;
+        let decoded = String::from_utf8_lossy(bytes);
+        if decoded.trim().is_empty() {
+            return Ok(());
+        }
+        let valid = matches!(decoded, Cow::Borrowed(_));
+        if !valid {
+            self.malformed_lines += 1;
+        }
+        let line = decoded.strip_suffix('\r').unwrap_or(&decoded);
+        let parse_start = Instant::now();
+        let row = self.extractor.extract(line, self.line_no);
+        self.parse_duration += parse_start.elapsed();
+        self.parsed_lines += 1;
+        if self.parsed_lines.is_multiple_of(FULL_PARSE_SAMPLE) {
+            let sample_start = Instant::now();
+            let root = serde_json::from_str(line).unwrap_or(Value::Null);
+            for path in self.engine.config.logs.values() {
+                black_box(extract_field(&root, path, line, self.line_no));
+            }
+            self.sampled_duration += sample_start.elapsed();
+            self.sampled_lines += 1;
+        }
+        let raw = match (self.options.storage, self.options.decode) {
+            (RawStorage::Offsets, _) => RawValue::Offset {
+                offset: offset as i64,
+                len: bytes.len() as i64,
+                text: line.to_string(),
+            },
+            (RawStorage::Inline, DecodeMode::Lossy) => {
+                RawValue::Inline(SqlValue::Text(line.to_string()))
+            }
+            (RawStorage::Inline, DecodeMode::Raw) if valid => {
+                RawValue::Inline(SqlValue::Text(decoded.into_owned()))
+            }
+            (RawStorage::Inline, DecodeMode::Raw) => {
+                RawValue::Inline(SqlValue::Blob(bytes.to_vec()))
+            }
+        };
+        self.buffer.push((raw, row));
+        if self.buffer.len() >= BATCH_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let db_start = Instant::now();
+        self.engine.db.insert_batch(&self.buffer)?;
+        let db_duration = db_start.elapsed();
+        self.db_duration += db_duration;
+        self.inserted_lines += self.buffer.len();
+        self.buffer.clear();
+        let total_duration = self.start_chunk.elapsed();
+        let read_duration = total_duration.saturating_sub(db_duration);
+        log::info!(
+            "Loaded {} lines. Read {:?}, SQLite {:?}, Total {:?}",
+            self.inserted_lines,
+            read_duration,
+            db_duration,
+            total_duration
+        );
+        self.start_chunk = Instant::now();
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<LoadStats> {
+        if !self.buffer.is_empty() {
+            self.flush()?;
+        }
+
+        // Offset storage fed the FTS index row by row in `insert_batch`.
+        let fts_start = Instant::now();
+        if self.options.storage == RawStorage::Inline {
+            self.engine.db.rebuild_fts()?;
+        }
+        let fts_duration = fts_start.elapsed();
+        self.db_duration += fts_duration;
+
+        let total_duration = self.start_total.elapsed();
+        let read_duration = total_duration.saturating_sub(self.db_duration);
+
+        Ok(LoadStats {
+            inserted_lines: self.inserted_lines,
+            malformed_lines: self.malformed_lines,
+            total_duration,
+            read_duration,
+            fts_duration,
+            db_duration: self.db_duration,
+            parse_duration: self.parse_duration,
+            full_parse_estimate: self.full_parse_estimate(),
+        })
+    }
+
+    fn full_parse_estimate(&self) -> Duration {
+        if self.sampled_lines == 0 {
+            return self.parse_duration;
+        }
+        self.sampled_duration
+            .mul_f64(self.parsed_lines as f64 / self.sampled_lines as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BATCH_SIZE, FULL_PARSE_SAMPLE};
+    use crate::{
+        Config, DecodeMode, Engine, LoadOptions, LoadStats, LogQuery, RawStorage, ReaderBackend,
+    };
+    use std::path::PathBuf;
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+no = "$lineno"
+"#;
+
+    /// A file in the temp directory holding `bytes`, removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, bytes: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "lkl2-loader-{}-{}.log",
+                std::process::id(),
+                name
+            ));
+            std::fs::write(&path, bytes).unwrap();
+            TempFile(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    /// An engine with `bytes` loaded from a file with `options`.
+    fn load(name: &str, bytes: &[u8], options: &LoadOptions) -> (Engine, LoadStats) {
+        let file = TempFile::new(name, bytes);
+        let mut engine = Engine::new(Config::parse(RULES).unwrap()).unwrap();
+        let stats = engine.load_file_with(&file.0, options).unwrap();
+        (engine, stats)
+    }
+
+    /// `level` and `no` of every row, and its `raw` bytes.
+    fn rows(engine: &Engine) -> Vec<(String, String, Vec<u8>)> {
+        let result = engine
+            .execute_query("SELECT id, level, no FROM logs ORDER BY id")
+            .unwrap();
+        result
+            .rows
+            .into_iter()
+            .map(|row| {
+                let id = row[0].as_deref().unwrap().parse().unwrap();
+                let raw = engine.get_raw(id).unwrap().unwrap();
+                let text = |i: usize| row[i].clone().unwrap_or_default();
+                (text(1), text(2), raw)
+            })
+            .collect()
+    }
+
+    fn row(level: &str, no: &str, raw: &[u8]) -> (String, String, Vec<u8>) {
+        (level.to_string(), no.to_string(), raw.to_vec())
+    }
+
+    fn decode(decode: DecodeMode) -> LoadOptions {
+        LoadOptions {
+            decode,
+            ..Default::default()
+        }
+    }
+
+    fn offsets() -> LoadOptions {
+        LoadOptions {
+            storage: RawStorage::Offsets,
+            reader: ReaderBackend::Mmap,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn offset_storage_rejects_other_loads() {
+        let first = TempFile::new("offsets-first", b"{\"level\":\"Info\"}\n");
+        let second = TempFile::new("offsets-second", b"{\"level\":\"Warn\"}\n");
+        let mut engine = Engine::new(Config::parse(RULES).unwrap()).unwrap();
+        engine.load_file_with(&first.0, &offsets()).unwrap();
+
+        let error = engine.load_file(&second.0).map(drop).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The engine uses offset storage, load with the same options"
+        );
+        assert!(engine.load_file_with(&second.0, &offsets()).is_err());
+        let count = engine.execute_query("SELECT COUNT(*) FROM logs").unwrap();
+        assert_eq!(count.rows[0][0].as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn read_raw_rejects_bad_ranges() {
+        let file = TempFile::new("read-raw", b"{\"level\":\"Info\"}\n");
+        let mut engine = Engine::new(Config::parse(RULES).unwrap()).unwrap();
+        engine.load_file_with(&file.0, &offsets()).unwrap();
+        let result = engine
+            .execute_query(
+                "SELECT read_raw(2, 5), read_raw(-1, 5), read_raw(0, -1), \
+                 read_raw(1, 9223372036854775807), \
+                 read_raw(9223372036854775807, 9223372036854775807), read_raw(0, 100)",
+            )
+            .unwrap();
+        assert_eq!(
+            result.rows[0],
+            [Some("level".to_string()), None, None, None, None, None]
+        );
+    }
+
+    #[test]
+    fn crlf_and_missing_final_newline() {
+        let input = b"{\"level\":\"Info\"}\r\n\r\n{\"level\":\"Warn\"}\n{\"level\":\"Error\"}";
+        let (engine, stats) = load("crlf-lossy", input, &decode(DecodeMode::Lossy));
+        assert_eq!(stats.inserted_lines, 3);
+        assert_eq!(stats.malformed_lines, 0);
+        // Blank lines are skipped but keep their line number.
+        assert_eq!(
+            rows(&engine),
+            [
+                row("Info", "1", br#"{"level":"Info"}"#),
+                row("Warn", "3", br#"{"level":"Warn"}"#),
+                row("Error", "4", br#"{"level":"Error"}"#),
+            ]
+        );
+        let (engine, _) = load("crlf-raw", input, &decode(DecodeMode::Raw));
+        assert_eq!(
+            rows(&engine)[0],
+            row("Info", "1", b"{\"level\":\"Info\"}\r")
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_is_counted_and_decoded() {
+        let input = b"{\"level\":\"W\xffrn\"}\n\xfe\n{\"level\":\"Info\"}\n";
+        let (engine, stats) = load("utf8-lossy", input, &decode(DecodeMode::Lossy));
+        assert_eq!(stats.inserted_lines, 3);
+        assert_eq!(stats.malformed_lines, 2);
+        assert_eq!(
+            rows(&engine),
+            [
+                row("W\u{fffd}rn", "1", "{\"level\":\"W\u{fffd}rn\"}".as_bytes()),
+                row("", "2", "\u{fffd}".as_bytes()),
+                row("Info", "3", br#"{"level":"Info"}"#),
+            ]
+        );
+
+        let (engine, stats) = load("utf8-raw", input, &decode(DecodeMode::Raw));
+        assert_eq!(stats.malformed_lines, 2);
+        assert_eq!(
+            rows(&engine),
+            [
+                row("W\u{fffd}rn", "1", b"{\"level\":\"W\xffrn\"}"),
+                row("", "2", b"\xfe"),
+                row("Info", "3", br#"{"level":"Info"}"#),
+            ]
+        );
+        let blobs = engine
+            .execute_query("SELECT COUNT(*) FROM logs WHERE typeof(raw) = 'blob'")
+            .unwrap();
+        assert_eq!(blobs.rows[0][0].as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn sampled_lines_load_like_the_others() {
+        let lines = 2 * FULL_PARSE_SAMPLE + 1;
+        let input: String = (1..=lines)
+            .map(|n| format!("{{\"level\":\"L{}\"}}\n", n))
+            .collect();
+        let (engine, stats) = load("sampled", input.as_bytes(), &LoadOptions::default());
+        assert_eq!(stats.inserted_lines, lines);
+        let rows = rows(&engine);
+        assert_eq!(rows.len(), lines);
+        for (i, (level, no, raw)) in rows.iter().enumerate() {
+            let n = (i + 1).to_string();
+            assert_eq!(*level, format!("L{}", n));
+            assert_eq!(*no, n);
+            assert_eq!(*raw, format!("{{\"level\":\"L{}\"}}", n).into_bytes());
+        }
+    }
+
+    #[test]
+    fn every_backend_loads_the_same_rows() {
+        let mut input = b"{\"level\":\"In\xfffo\"}\r\n\n  \n\xfe\n".to_vec();
+        for n in 0..BATCH_SIZE {
+            input.extend_from_slice(format!("{{\"level\":\"L{}\"}}\n", n).as_bytes());
+        }
+        input.extend_from_slice(b"{\"level\":\"last\"}");
+        let search = LogQuery {
+            fts_query: "L99".to_string(),
+            ..Default::default()
+        };
+
+        for decode in [DecodeMode::Lossy, DecodeMode::Raw] {
+            let mut loaded = Vec::new();
+            for storage in [RawStorage::Inline, RawStorage::Offsets] {
+                for reader in [ReaderBackend::Buffered, ReaderBackend::Mmap] {
+                    let options = LoadOptions {
+                        decode,
+                        storage,
+                        reader,
+                    };
+                    let name = format!("backend-{:?}-{:?}-{:?}", decode, storage, reader);
+                    let (engine, stats) = load(&name, &input, &options);
+                    let found = engine.get_logs(&search).unwrap().total_count;
+                    loaded.push((
+                        options,
+                        stats.inserted_lines,
+                        stats.malformed_lines,
+                        found,
+                        rows(&engine),
+                    ));
+                }
+            }
+            let (_, inserted, malformed, found, rows) = &loaded[0];
+            assert_eq!((*inserted, *malformed, *found), (BATCH_SIZE + 3, 2, 11));
+            for (options, other_inserted, other_malformed, other_found, other_rows) in &loaded[1..]
+            {
+                assert_eq!(
+                    (other_inserted, other_malformed, other_found),
+                    (inserted, malformed, found),
+                    "{:?}",
+                    options
+                );
+                assert!(other_rows == rows, "{:?}", options);
+            }
+        }
+    }
+}
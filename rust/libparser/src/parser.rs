@@ -1,6 +1,11 @@
+use serde::de::{self, Deserialize, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::value::RawValue;
 use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 
-pub fn extract_field(root: &Value, path: &str, line: &str, line_no: usize) -> String {
+pub(crate) fn extract_field(root: &Value, path: &str, line: &str, line_no: usize) -> String {
     if path == "$lineno" {
         return line_no.to_string();
     }
@@ -38,3 +43,369 @@ pub fn extract_field(root: &Value, path: &str, line: &str, line_no: usize) -> St
         v => v.to_string(),
     }
 }
+
+/// Where a column's value comes from.
+enum Source {
+    LineNo,
+    Line,
+    /// A `$line.*` path, filled in while walking the JSON.
+    Json,
+    /// An unrecognized path, always empty.
+    Empty,
+}
+
+/// A key in the compiled path tree. `columns` end here; `all` are the JSON
+/// columns at or below this node.
+#[derive(Default)]
+struct Node {
+    children: HashMap<String, Node>,
+    columns: Vec<usize>,
+    all: Vec<usize>,
+}
+
+/// The `[logs]` paths compiled into a tree of object keys. Each line is
+/// parsed in a single pass that descends only into members some path goes
+/// through; every other member is skipped without being built. The result is
+/// the same as calling [`extract_field`] on the fully parsed line.
+pub struct FieldExtractor {
+    columns: Vec<(String, Source)>,
+    root: Node,
+}
+
+impl FieldExtractor {
+    pub fn new(paths: &HashMap<String, String>) -> Self {
+        let mut columns = Vec::with_capacity(paths.len());
+        let mut root = Node::default();
+        for (i, (col, path)) in paths.iter().enumerate() {
+            let source = if path == "$lineno" {
+                Source::LineNo
+            } else if path == "$line" || path == "$0" {
+                Source::Line
+            } else if let Some(path) = path
+                .strip_prefix("$line.")
+                .or_else(|| path.strip_prefix("$0."))
+            {
+                let mut node = &mut root;
+                node.all.push(i);
+                for part in path.split('.') {
+                    node = node.children.entry(part.to_string()).or_default();
+                    node.all.push(i);
+                }
+                node.columns.push(i);
+                Source::Json
+            } else {
+                Source::Empty
+            };
+            columns.push((col.clone(), source));
+        }
+        FieldExtractor { columns, root }
+    }
+
+    pub fn extract(&self, line: &str, line_no: usize) -> HashMap<String, String> {
+        let mut values = vec![String::new(); self.columns.len()];
+        if !self.root.all.is_empty() && walk(&self.root, line, &mut values).is_err() {
+            // Like `extract_field` on `Value::Null`: an unparsable line has no
+            // fields, even if some were found before the error.
+            for &i in &self.root.all {
+                values[i].clear();
+            }
+        }
+        self.columns
+            .iter()
+            .zip(values)
+            .map(|((col, source), value)| {
+                let value = match source {
+                    Source::LineNo => line_no.to_string(),
+                    Source::Line => line.to_string(),
+                    Source::Json | Source::Empty => value,
+                };
+                (col.clone(), value)
+            })
+            .collect()
+    }
+}
+
+/// Fills the columns below `node` from the JSON text `json`, which must be a
+/// complete document.
+fn walk(node: &Node, json: &str, values: &mut [String]) -> serde_json::Result<()> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    Members { node, values }.deserialize(&mut de)?;
+    de.end()
+}
+
+/// Visits a value, descending into the members of an object that `node` has
+/// children for and skipping everything else. Values that are not objects
+/// have no members. With duplicate keys the last one wins, as in [`Value`].
+struct Members<'n, 'v> {
+    node: &'n Node,
+    values: &'v mut [String],
+}
+
+impl<'de> DeserializeSeed<'de> for Members<'_, '_> {
+    type Value = ();
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Members<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<Key<'de>>()? {
+            let Some(child) = self.node.children.get(key.0.as_ref()) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            for &i in &child.all {
+                self.values[i].clear();
+            }
+            if child.children.is_empty() {
+                let value = map.next_value_seed(Leaf)?;
+                for &i in &child.columns {
+                    self.values[i].clone_from(&value);
+                }
+            } else if child.columns.is_empty() {
+                map.next_value_seed(Members {
+                    node: child,
+                    values: self.values,
+                })?;
+            } else {
+                // Both this member and members below it are columns.
+                let raw: &RawValue = map.next_value()?;
+                let value = render(raw.get());
+                for &i in &child.columns {
+                    self.values[i].clone_from(&value);
+                }
+                walk(child, raw.get(), self.values).map_err(de::Error::custom)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+}
+
+/// Formats the value a path ends at the way [`extract_field`] does.
+struct Leaf;
+
+impl<'de> DeserializeSeed<'de> for Leaf {
+    type Value = String;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<String, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Leaf {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<String, A::Error> {
+        Ok(Value::deserialize(de::value::MapAccessDeserializer::new(map))?.to_string())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<String, A::Error> {
+        Ok(Value::deserialize(de::value::SeqAccessDeserializer::new(seq))?.to_string())
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<String, E> {
+        Ok(Value::from(v).to_string())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<String, E> {
+        Ok(String::new())
+    }
+}
+
+/// Formats a JSON value the way [`extract_field`] does.
+fn render(json: &str) -> String {
+    let json = json.trim();
+    if json.starts_with('"') {
+        serde_json::from_str::<Cow<str>>(json)
+            .map(Cow::into_owned)
+            .unwrap_or_default()
+    } else if json == "null" {
+        String::new()
+    } else {
+        // Numbers and nested values are re-serialized so they print exactly
+        // as `Value` prints them.
+        serde_json::from_str::<Value>(json)
+            .map(|v| v.to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// An object key, borrowed from the input unless it contains escapes.
+struct Key<'de>(Cow<'de, str>);
+
+impl<'de> Deserialize<'de> for Key<'de> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyVisitor;
+
+        impl<'de> Visitor<'de> for KeyVisitor {
+            type Value = Key<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(Key(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(Key(Cow::Owned(v.to_string())))
+            }
+        }
+
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks every path against [`extract_field`] on the fully parsed line.
+    fn assert_matches_full_parse(paths: &[&str], line: &str) -> HashMap<String, String> {
+        let paths: HashMap<String, String> = paths
+            .iter()
+            .enumerate()
+            .map(|(i, path)| (format!("c{}", i), path.to_string()))
+            .collect();
+        let values = FieldExtractor::new(&paths).extract(line, 7);
+        let root = serde_json::from_str(line).unwrap_or(Value::Null);
+        for (col, path) in &paths {
+            assert_eq!(
+                values[col],
+                extract_field(&root, path, line, 7),
+                "{} in {}",
+                path,
+                line
+            );
+        }
+        values
+    }
+
+    #[test]
+    fn variables() {
+        let line = r#"{"a":1}"#;
+        let values = assert_matches_full_parse(&["$lineno", "$line", "$0", "$other.a"], line);
+        assert_eq!(values["c0"], "7");
+        assert_eq!(values["c1"], line);
+        assert_eq!(values["c3"], "");
+    }
+
+    #[test]
+    fn walks_nested_paths_and_skips_other_members() {
+        let line = r#"{"skip":{"deep":[1,{"a":2}]},"a":{"b":{"c":"x"},"n":1.5e3},"t":true}"#;
+        let values =
+            assert_matches_full_parse(&["$line.a.b.c", "$0.a.n", "$line.t", "$line.a.x"], line);
+        assert_eq!(values["c0"], "x");
+        assert_eq!(values["c1"], "1500.0");
+    }
+
+    #[test]
+    fn renders_objects_arrays_and_null() {
+        let line = r#"{"o":{"k":[1, "two"]},"arr":[{"x":1}],"nul":null,"s":"q\"uote"}"#;
+        let values = assert_matches_full_parse(
+            &[
+                "$line.o",
+                "$line.arr",
+                "$line.nul",
+                "$line.s",
+                "$line.arr.x",
+            ],
+            line,
+        );
+        assert_eq!(values["c0"], r#"{"k":[1,"two"]}"#);
+        assert_eq!(values["c2"], "");
+        assert_eq!(values["c3"], "q\"uote");
+    }
+
+    #[test]
+    fn a_member_and_members_below_it() {
+        let line = r#"{"a":{"b":1,"c":{"d":"e"}}}"#;
+        let values = assert_matches_full_parse(&["$line.a", "$line.a.b", "$line.a.c.d"], line);
+        assert_eq!(values["c0"], r#"{"b":1,"c":{"d":"e"}}"#);
+        assert_eq!(values["c2"], "e");
+    }
+
+    #[test]
+    fn last_duplicate_key_wins() {
+        assert_matches_full_parse(&["$line.a.b", "$line.a"], r#"{"a":{"b":1},"a":{"c":2}}"#);
+        assert_matches_full_parse(&["$line.a"], r#"{"a":1,"a":null}"#);
+    }
+
+    #[test]
+    fn escaped_keys() {
+        let values = assert_matches_full_parse(&["$line.k\ney"], r#"{"k\ney":"v"}"#);
+        assert_eq!(values["c0"], "v");
+    }
+
+    #[test]
+    fn malformed_lines_have_no_fields() {
+        for line in [
+            r#"{"a":1,"b":"#,
+            r#"{"a":1} trailing"#,
+            "plain text",
+            r#"[{"a":1}]"#,
+            "3",
+        ] {
+            let values = assert_matches_full_parse(&["$line.a", "$line"], line);
+            assert_eq!(values["c0"], "");
+        }
+    }
+}
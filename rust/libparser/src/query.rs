@@ -0,0 +1,492 @@
+use crate::search::{MatchSpan, Search, SearchOptions, SearchStrategy};
+use crate::{Engine, QueryResult};
+use anyhow::Result;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How a column is compared when sorting. Every column is stored as TEXT, so
+/// numeric orders are obtained by casting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortType {
+    #[default]
+    Text,
+    /// `CAST(col AS INTEGER)`; values beyond the i64 range saturate.
+    Integer,
+    Real,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SortKey {
+    pub column: String,
+    #[serde(default)]
+    pub descending: bool,
+    #[serde(default)]
+    pub sort_type: SortType,
+}
+
+/// A filtered, sorted page of `logs`, as shown by the app's log list.
+/// Missing fields deserialize to their defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LogQuery {
+    /// SQL WHERE clause fragment, e.g. `eventName = 'Error'`.
+    pub filter_sql: String,
+    /// Full text search over `raw`, interpreted according to `search_options`.
+    pub fts_query: String,
+    pub search_options: SearchOptions,
+    /// Sort keys in priority order; rows always fall back to load order.
+    pub sort: Vec<SortKey>,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+pub struct LogPage {
+    /// Number of rows matching the filters, ignoring limit/offset.
+    pub total_count: u64,
+    /// `id` and the configured columns of the requested page.
+    pub result: QueryResult,
+    /// Where `fts_query` occurs in each row of `result`, in the same order.
+    /// Spans cover the configured columns and `raw`; empty without a search.
+    pub matches: Vec<Vec<MatchSpan>>,
+    /// How the full text search was evaluated; `None` without a search.
+    pub strategy: Option<SearchStrategy>,
+    /// The `[[col]]` cells of each row of `result`, see
+    /// [`Engine::render_cells`].
+    pub cells: Vec<Vec<String>>,
+}
+
+/// One row of a [`LogPage`] or [`Engine::get_context`] result, split into
+/// its `id` and the other columns.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogRecord {
+    pub id: i64,
+    pub fields: HashMap<String, String>,
+    /// Where `fts_query` occurs in the row; empty without a search.
+    pub matches: Vec<MatchSpan>,
+    /// The rendered `[[col]]` cells; empty unless rendered by the engine.
+    pub cells: Vec<String>,
+}
+
+impl LogPage {
+    /// The rows of the page with their matches and cells.
+    pub fn into_records(self) -> Vec<LogRecord> {
+        let mut records = self.result.into_records();
+        for ((record, matches), cells) in records.iter_mut().zip(self.matches).zip(self.cells) {
+            record.matches = matches;
+            record.cells = cells;
+        }
+        records
+    }
+}
+
+impl QueryResult {
+    /// Splits each row into its `id` column, which is 0 when missing, and
+    /// the remaining columns, NULL ones as empty strings. See
+    /// [`Engine::records`] to also render the cells.
+    pub fn into_records(self) -> Vec<LogRecord> {
+        let mut records = Vec::with_capacity(self.rows.len());
+        for row in self.rows {
+            let mut fields = HashMap::new();
+            let mut id = 0;
+            for (column, value) in self.headers.iter().zip(row) {
+                if column == "id" {
+                    id = value.and_then(|v| v.parse().ok()).unwrap_or(0);
+                } else {
+                    fields.insert(column.clone(), value.unwrap_or_default());
+                }
+            }
+            records.push(LogRecord {
+                id,
+                fields,
+                matches: Vec::new(),
+                cells: Vec::new(),
+            });
+        }
+        records
+    }
+}
+
+impl SortKey {
+    fn expr(&self) -> String {
+        match self.sort_type {
+            SortType::Text => self.column.clone(),
+            SortType::Integer => format!("CAST({} AS INTEGER)", self.column),
+            SortType::Real => format!("CAST({} AS REAL)", self.column),
+        }
+    }
+
+    fn order_term(&self) -> String {
+        if self.descending {
+            format!("{} DESC", self.expr())
+        } else {
+            self.expr()
+        }
+    }
+
+    /// Names this key in a sort index name. Column names may contain `_`,
+    /// so the column is prefixed with its length to keep the names of
+    /// different sorts apart.
+    fn index_suffix(&self) -> String {
+        let ty = match self.sort_type {
+            SortType::Text => "text",
+            SortType::Integer => "int",
+            SortType::Real => "real",
+        };
+        let dir = if self.descending { "desc" } else { "asc" };
+        format!("{}{}_{}_{}", self.column.len(), self.column, ty, dir)
+    }
+}
+
+impl LogQuery {
+    /// The compiled full text search, if `fts_query` is not blank.
+    pub(crate) fn search(&self) -> Result<Option<Search>> {
+        if self.fts_query.trim().is_empty() {
+            return Ok(None);
+        }
+        Search::new(&self.fts_query, self.search_options).map(Some)
+    }
+
+    pub(crate) fn where_clause(&self) -> Result<String> {
+        let mut where_clauses = Vec::new();
+
+        if let Some(search) = self.search()? {
+            where_clauses.push(search.condition);
+        }
+
+        if !self.filter_sql.trim().is_empty() {
+            where_clauses.push(format!("({})", self.filter_sql));
+        }
+
+        Ok(if where_clauses.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", where_clauses.join(" AND "))
+        })
+    }
+
+    pub(crate) fn order_clause(&self) -> String {
+        let mut terms: Vec<String> = self.sort.iter().map(SortKey::order_term).collect();
+        terms.push("id".to_string());
+        format!("ORDER BY {}", terms.join(", "))
+    }
+}
+
+impl Engine {
+    /// Returns the rows surrounding `id` in load order, like `grep -C`.
+    ///
+    /// When `same_field` is set, only neighbours sharing the anchor row's value of
+    /// that column are counted (e.g. the same `sourceNodeId`). The anchor row is
+    /// always part of the result; an unknown id yields no rows.
+    pub fn get_context(
+        &self,
+        id: i64,
+        before: u32,
+        after: u32,
+        same_field: Option<&str>,
+    ) -> Result<QueryResult> {
+        let cond = match same_field {
+            Some(field) => {
+                self.check_column(field)?;
+                format!("AND {field} IS (SELECT {field} FROM logs WHERE id = ?1)")
+            }
+            None => String::new(),
+        };
+        let cols = self.select_list();
+        let query = format!(
+            "SELECT {cols} FROM (\
+             SELECT * FROM (SELECT {cols} FROM logs WHERE id < ?1 {cond} ORDER BY id DESC LIMIT ?2) \
+             UNION ALL SELECT {cols} FROM logs WHERE id = ?1 \
+             UNION ALL SELECT * FROM (SELECT {cols} FROM logs WHERE id > ?1 {cond} ORDER BY id LIMIT ?3)\
+             ) WHERE EXISTS (SELECT 1 FROM logs WHERE id = ?1) ORDER BY id"
+        );
+        self.execute_query_with(&query, params![id, before, after])
+    }
+
+    pub fn get_logs(&self, query: &LogQuery) -> Result<LogPage> {
+        self.check_sort(&query.sort)?;
+        self.ensure_sort_index(&query.sort)?;
+
+        let search = query.search()?;
+        let where_str = query.where_clause()?;
+        let count_query = format!("SELECT COUNT(*) FROM logs {}", where_str);
+        let total_count: i64 = self.db.conn.query_row(&count_query, [], |row| row.get(0))?;
+
+        let mut cols = self.select_list();
+        if search.is_some() {
+            // Fetched only to locate the matches; dropped from the result below.
+            cols.push_str(", raw");
+        }
+        let data_query = format!(
+            "SELECT {} FROM logs {} {} LIMIT {} OFFSET {}",
+            cols,
+            where_str,
+            query.order_clause(),
+            query.limit,
+            query.offset
+        );
+        let mut result = self.execute_query(&data_query)?;
+
+        let mut matches = vec![Vec::new(); result.rows.len()];
+        if let Some(search) = &search {
+            for (row, spans) in result.rows.iter().zip(matches.iter_mut()) {
+                *spans = search.row_matches(&result.headers, row);
+            }
+            result.headers.pop();
+            for row in &mut result.rows {
+                row.pop();
+            }
+        }
+        let cells = self.render_cells(&result);
+        Ok(LogPage {
+            total_count: total_count as u64,
+            result,
+            matches,
+            strategy: search.map(|s| s.strategy),
+            cells,
+        })
+    }
+
+    /// Fails unless every key sorts by `id`, `ts` or a configured column.
+    pub(crate) fn check_sort(&self, sort: &[SortKey]) -> Result<()> {
+        for key in sort {
+            let is_ts = key.column == "ts" && self.db.time_field.is_some();
+            if key.column != "id" && !is_ts {
+                self.check_column(&key.column)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates an index matching the sort keys the first time they are used,
+    /// so paging through a large file in that order stays interactive.
+    pub(crate) fn ensure_sort_index(&self, sort: &[SortKey]) -> Result<()> {
+        if sort.iter().all(|key| key.column == "id") {
+            return Ok(());
+        }
+        let name: Vec<String> = sort.iter().map(SortKey::index_suffix).collect();
+        let terms: Vec<String> = sort.iter().map(SortKey::order_term).collect();
+        self.db
+            .create_index(&format!("logs_sort_{}", name.join("_")), &terms.join(", "))
+    }
+
+    /// Fails unless `name` is one of the configured columns.
+    pub(crate) fn check_column(&self, name: &str) -> Result<()> {
+        if !self.db.columns.iter().any(|c| c == name) {
+            anyhow::bail!("Unknown column '{}'", name);
+        }
+        Ok(())
+    }
+
+    /// `id` plus the configured columns, i.e. everything but `raw`.
+    pub(crate) fn select_list(&self) -> String {
+        let mut cols = Vec::with_capacity(self.db.columns.len() + 1);
+        cols.push("id");
+        cols.extend(self.db.columns.iter().map(String::as_str));
+        cols.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    const RULES: &str = r#"
+[logs]
+node = "$line.node"
+n = "$line.n"
+"#;
+
+    /// Six rows alternating between nodes `a` and `b`; row 5 has no node.
+    fn engine() -> Engine {
+        test_util::engine(
+            RULES,
+            &[
+                r#"{"node":"a","n":"1"}"#,
+                r#"{"node":"b","n":"2"}"#,
+                r#"{"node":"a","n":"3"}"#,
+                r#"{"node":"b","n":"4"}"#,
+                r#"{"n":"5"}"#,
+                r#"{"node":"b","n":"6"}"#,
+            ],
+        )
+    }
+
+    fn ids(result: &QueryResult) -> Vec<i64> {
+        result
+            .rows
+            .iter()
+            .map(|row| row[0].as_deref().unwrap().parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn context_around_a_row() {
+        let engine = engine();
+        let result = engine.get_context(3, 1, 2, None).unwrap();
+        assert_eq!(result.headers, ["id", "n", "node"]);
+        assert_eq!(ids(&result), [2, 3, 4, 5]);
+        assert_eq!(ids(&engine.get_context(3, 0, 0, None).unwrap()), [3]);
+    }
+
+    #[test]
+    fn context_at_the_ends_of_the_file() {
+        let engine = engine();
+        assert_eq!(ids(&engine.get_context(1, 3, 1, None).unwrap()), [1, 2]);
+        assert_eq!(ids(&engine.get_context(6, 2, 3, None).unwrap()), [4, 5, 6]);
+        assert_eq!(
+            ids(&engine.get_context(2, 100, 100, None).unwrap()),
+            [1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn context_of_the_same_field() {
+        let engine = engine();
+        let same =
+            |id, before, after| ids(&engine.get_context(id, before, after, Some("node")).unwrap());
+        assert_eq!(same(4, 1, 1), [2, 4, 6]);
+        assert_eq!(same(3, 5, 5), [1, 3]);
+        // A missing value is a value too.
+        assert_eq!(same(5, 1, 1), [5]);
+        assert!(engine.get_context(3, 1, 1, Some("nope")).is_err());
+    }
+
+    #[test]
+    fn context_of_an_unknown_id() {
+        let engine = engine();
+        let result = engine.get_context(7, 2, 2, None).unwrap();
+        assert_eq!(result.headers, ["id", "n", "node"]);
+        assert!(result.rows.is_empty());
+        assert!(engine
+            .get_context(0, 2, 2, Some("node"))
+            .unwrap()
+            .rows
+            .is_empty());
+    }
+
+    #[test]
+    fn pages_of_logs() {
+        let engine = engine();
+        let query = LogQuery {
+            filter_sql: "node = 'b'".to_string(),
+            limit: 2,
+            offset: 1,
+            ..Default::default()
+        };
+        let page = engine.get_logs(&query).unwrap();
+        assert_eq!(page.total_count, 3);
+        assert_eq!(ids(&page.result), [4, 6]);
+        assert_eq!(page.matches, [Vec::new(), Vec::new()]);
+        assert_eq!(page.strategy, None);
+
+        let records = engine
+            .get_logs(&LogQuery {
+                limit: 10,
+                ..Default::default()
+            })
+            .unwrap()
+            .into_records();
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[4].id, 5);
+        assert_eq!(records[4].fields["node"], "");
+        assert_eq!(records[4].fields["n"], "5");
+
+        assert!(engine
+            .get_logs(&LogQuery {
+                filter_sql: "nope = 1".to_string(),
+                ..Default::default()
+            })
+            .is_err());
+    }
+
+    fn sorted(engine: &Engine, sort: &[(&str, bool, SortType)]) -> Result<Vec<i64>> {
+        let query = LogQuery {
+            sort: sort
+                .iter()
+                .map(|&(column, descending, sort_type)| SortKey {
+                    column: column.to_string(),
+                    descending,
+                    sort_type,
+                })
+                .collect(),
+            limit: 100,
+            ..Default::default()
+        };
+        Ok(ids(&engine.get_logs(&query)?.result))
+    }
+
+    #[test]
+    fn sorts_by_type_and_direction() {
+        let engine = test_util::engine(
+            RULES,
+            &[
+                r#"{"node":"b","n":"10"}"#,
+                r#"{"node":"a","n":"9"}"#,
+                r#"{"node":"b","n":"-1.5"}"#,
+                r#"{"node":"a","n":"2.25"}"#,
+                r#"{"node":"b","n":"9"}"#,
+            ],
+        );
+        use SortType::{Integer, Real, Text};
+        assert_eq!(
+            sorted(&engine, &[("n", false, Text)]).unwrap(),
+            [3, 1, 4, 2, 5]
+        );
+        assert_eq!(
+            sorted(&engine, &[("n", false, Real)]).unwrap(),
+            [3, 4, 2, 5, 1]
+        );
+        // Ties fall back to load order, also when descending.
+        assert_eq!(
+            sorted(&engine, &[("n", true, Integer)]).unwrap(),
+            [1, 2, 5, 4, 3]
+        );
+        assert_eq!(
+            sorted(&engine, &[("node", true, Text), ("n", false, Real)]).unwrap(),
+            [3, 5, 1, 4, 2]
+        );
+        assert_eq!(
+            sorted(&engine, &[("id", true, Text)]).unwrap(),
+            [5, 4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn sorts_only_by_known_columns() {
+        let engine = engine();
+        for column in ["nope", "raw", "ts", "n; DROP TABLE logs", "n DESC, node"] {
+            let error = sorted(&engine, &[(column, false, SortType::Text)]).unwrap_err();
+            assert_eq!(error.to_string(), format!("Unknown column '{}'", column));
+        }
+    }
+
+    #[test]
+    fn sort_index_names_do_not_collide() {
+        let rules = r#"
+[logs]
+a = "$line.a"
+b = "$line.b"
+a_text_asc_b = "$line.c"
+"#;
+        let engine = test_util::engine(
+            rules,
+            &[
+                r#"{"a":"1","b":"2","c":"1"}"#,
+                r#"{"a":"1","b":"1","c":"2"}"#,
+            ],
+        );
+        let text = SortType::Text;
+        // Both were named logs_sort_a_text_asc_b_text_asc.
+        let two_keys = [("a", false, text), ("b", false, text)];
+        let one_key = [("a_text_asc_b", false, text)];
+        assert_eq!(sorted(&engine, &two_keys).unwrap(), [2, 1]);
+        assert_eq!(sorted(&engine, &one_key).unwrap(), [1, 2]);
+        let indexes = engine
+            .execute_query("SELECT sql FROM sqlite_master WHERE name LIKE 'logs_sort%'")
+            .unwrap();
+        assert_eq!(indexes.rows.len(), 2);
+    }
+}
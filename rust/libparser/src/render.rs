@@ -0,0 +1,152 @@
+use crate::expr::Expr;
+use crate::query::LogRecord;
+use crate::{Engine, QueryResult};
+use std::collections::HashMap;
+
+/// Renders rows with the given columns into the cells of the layout.
+pub(crate) struct Renderer<'a> {
+    exprs: Vec<&'a Expr>,
+    columns: HashMap<String, usize>,
+}
+
+impl Renderer<'_> {
+    /// One string per layout cell; fields that are NULL or missing from the
+    /// row are empty.
+    pub fn render(&self, row: &[Option<String>]) -> Vec<String> {
+        let field = |name: &str| {
+            self.columns
+                .get(name)
+                .and_then(|&i| row.get(i))
+                .and_then(Option::as_deref)
+                .unwrap_or_default()
+        };
+        self.exprs.iter().map(|expr| expr.eval(&field)).collect()
+    }
+}
+
+impl Engine {
+    /// The expressions of the `[[col]]` cells, column by column, top to
+    /// bottom. Rendered cells come in this order.
+    pub fn cell_headers(&self) -> Vec<String> {
+        self.config.cells().map(|c| c.expr.to_string()).collect()
+    }
+
+    /// Renders the `[[col]]` cells of every row of `result`, so every client
+    /// shows the same text. `result` should hold the columns the cells read,
+    /// as [`Engine::get_logs`] and [`Engine::get_context`] results do.
+    pub fn render_cells(&self, result: &QueryResult) -> Vec<Vec<String>> {
+        let renderer = self.renderer(&result.headers);
+        result.rows.iter().map(|row| renderer.render(row)).collect()
+    }
+
+    /// [`QueryResult::into_records`] with the cells rendered.
+    pub fn records(&self, result: QueryResult) -> Vec<LogRecord> {
+        let cells = self.render_cells(&result);
+        let mut records = result.into_records();
+        for (record, cells) in records.iter_mut().zip(cells) {
+            record.cells = cells;
+        }
+        records
+    }
+
+    pub(crate) fn renderer(&self, headers: &[String]) -> Renderer<'_> {
+        Renderer {
+            exprs: self.config.cells().map(|c| &c.expr).collect(),
+            columns: headers
+                .iter()
+                .enumerate()
+                .map(|(i, h)| (h.clone(), i))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::CellStyle;
+    use crate::test_util;
+    use crate::{Engine, LogQuery};
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+node = "$line.node"
+n = "$line.n"
+
+[[col]]
+width = 60
+expr = "$level"
+style = "tag"
+
+[[col]]
+[[col.row]]
+expr = "concat($node, ':', $n)"
+style = "meta"
+[[col.row]]
+expr = "hex($n)"
+style = "colortag"
+"#;
+
+    fn engine() -> Engine {
+        test_util::engine(
+            RULES,
+            &[
+                r#"{"level":"Warn","node":"a","n":"255"}"#,
+                r#"{"level":"Error","n":"16"}"#,
+            ],
+        )
+    }
+
+    #[test]
+    fn cells_follow_the_layout() {
+        let engine = engine();
+        let styles: Vec<CellStyle> = engine.config().cells().map(|c| c.style).collect();
+        assert_eq!(
+            styles,
+            [CellStyle::Tag, CellStyle::Meta, CellStyle::ColorTag]
+        );
+        assert_eq!(
+            engine.cell_headers(),
+            ["$level", "concat($node, ':', $n)", "hex($n)"]
+        );
+        let page = engine
+            .get_logs(&LogQuery {
+                limit: 10,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            page.cells,
+            [["Warn", "a:255", "ff"], ["Error", ":16", "10"]]
+        );
+        let records = engine.records(engine.get_context(2, 1, 0, None).unwrap());
+        let cells: Vec<_> = records.iter().map(|r| (r.id, r.cells.clone())).collect();
+        assert_eq!(
+            cells,
+            [
+                (1, vec!["Warn".to_string(), "a:255".into(), "ff".into()]),
+                (2, vec!["Error".to_string(), ":16".into(), "10".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn null_and_missing_fields_render_empty() {
+        let engine = engine();
+        let result = engine
+            .execute_query("SELECT NULL AS level, n FROM logs ORDER BY id")
+            .unwrap();
+        assert_eq!(
+            engine.render_cells(&result),
+            [["", ":255", "ff"], ["", ":16", "10"]]
+        );
+    }
+
+    #[test]
+    fn no_layout_renders_no_cells() {
+        let engine = test_util::engine("[logs]\nlevel = \"$line.level\"\n", &["{}"]);
+        let result = engine.execute_query("SELECT * FROM logs").unwrap();
+        assert!(engine.cell_headers().is_empty());
+        assert_eq!(engine.render_cells(&result), [Vec::<String>::new()]);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::config::Config;
+use crate::parser::FieldExtractor;
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// What the `[logs]` paths of a rule file extract from a few sample lines,
+/// to try rules out before loading a large file.
+pub struct DryRun {
+    /// The `[logs]` columns, sorted by name like in the `logs` table.
+    pub columns: Vec<String>,
+    /// One row per sample line that is not blank.
+    pub rows: Vec<DryRunRow>,
+    /// Columns that were empty on every sample line, usually because their
+    /// path does not match the logs.
+    pub empty_columns: Vec<String>,
+}
+
+pub struct DryRunRow {
+    /// 1-based number of the line in the sample, as `$lineno` gives it.
+    pub line_no: usize,
+    /// The values in `columns` order.
+    pub values: Vec<String>,
+}
+
+impl Config {
+    /// Extracts the `[logs]` columns from `lines` the way loading a file
+    /// starting with these lines would.
+    pub fn dry_run(&self, lines: &[impl AsRef<str>]) -> DryRun {
+        let mut columns: Vec<String> = self.logs.keys().cloned().collect();
+        columns.sort();
+        let extractor = FieldExtractor::new(&self.logs);
+        let rows: Vec<DryRunRow> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.as_ref().trim().is_empty())
+            .map(|(i, line)| {
+                let mut fields = extractor.extract(line.as_ref(), i + 1);
+                DryRunRow {
+                    line_no: i + 1,
+                    values: columns
+                        .iter()
+                        .map(|col| fields.remove(col).unwrap_or_default())
+                        .collect(),
+                }
+            })
+            .collect();
+        let empty_columns = columns
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| rows.iter().all(|row| row.values[i].is_empty()))
+            .map(|(_, col)| col.clone())
+            .collect();
+        DryRun {
+            columns,
+            rows,
+            empty_columns,
+        }
+    }
+}
+
+/// The first `limit` lines of the file at `path`, decoded like `load_file`
+/// decodes them: invalid UTF-8 becomes U+FFFD and `\r\n` endings are dropped.
+pub fn read_sample(path: impl AsRef<Path>, limit: usize) -> Result<Vec<String>> {
+    let path = path.as_ref();
+    let file = File::open(path).with_context(|| format!("Failed to open file {:?}", path))?;
+    let mut reader = BufReader::new(file);
+    let mut lines = Vec::new();
+    let mut buf = Vec::new();
+    while lines.len() < limit {
+        buf.clear();
+        let read = reader
+            .read_until(b'\n', &mut buf)
+            .with_context(|| format!("Failed to read file {:?}", path))?;
+        if read == 0 {
+            break;
+        }
+        let line = String::from_utf8_lossy(&buf);
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        lines.push(line.strip_suffix('\r').unwrap_or(line).to_string());
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: &str = r#"
+[logs]
+level = "$line.level"
+no = "$lineno"
+msg = "$line.msg"
+typo = "$line.levle"
+"#;
+
+    /// The lines `read_sample` returns for a file holding `bytes`.
+    fn sample(name: &str, bytes: &[u8], limit: usize) -> Vec<String> {
+        let path =
+            std::env::temp_dir().join(format!("lkl2-sample-{}-{}.log", std::process::id(), name));
+        std::fs::write(&path, bytes).unwrap();
+        let lines = read_sample(&path, limit).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        lines
+    }
+
+    #[test]
+    fn reads_lines_like_loading_does() {
+        let bytes = b"a\r\nb\xffc\n\n d \r\ne";
+        assert_eq!(
+            sample("decode", bytes, 100),
+            ["a", "b\u{fffd}c", "", " d ", "e"]
+        );
+        assert_eq!(sample("limit", bytes, 2), ["a", "b\u{fffd}c"]);
+        assert!(sample("zero", bytes, 0).is_empty());
+        assert!(sample("empty", b"", 10).is_empty());
+        assert!(read_sample("/nonexistent/lkl2.log", 10).is_err());
+    }
+
+    #[test]
+    fn dry_run_keeps_line_numbers() {
+        let config = Config::parse(RULES).unwrap();
+        let lines = [
+            r#"{"level":"Info","msg":"up"}"#,
+            "",
+            "   ",
+            r#"{"level":"Warn"}"#,
+            "not json",
+        ];
+        let dry_run = config.dry_run(&lines);
+        assert_eq!(dry_run.columns, ["level", "msg", "no", "typo"]);
+        let rows: Vec<(usize, Vec<String>)> = dry_run
+            .rows
+            .into_iter()
+            .map(|row| (row.line_no, row.values))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                (
+                    1,
+                    vec!["Info".into(), "up".into(), "1".into(), String::new()]
+                ),
+                (
+                    4,
+                    vec!["Warn".into(), String::new(), "4".into(), String::new()]
+                ),
+                (
+                    5,
+                    vec![String::new(), String::new(), "5".into(), String::new()]
+                ),
+            ]
+        );
+        assert_eq!(dry_run.empty_columns, ["typo"]);
+    }
+
+    #[test]
+    fn dry_run_of_nothing() {
+        let config = Config::parse(RULES).unwrap();
+        let dry_run = config.dry_run(&["", "  "]);
+        assert!(dry_run.rows.is_empty());
+        assert_eq!(dry_run.empty_columns, dry_run.columns);
+    }
+}
@@ -0,0 +1,612 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// How `LogQuery::fts_query` is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    /// The whole query as a substring.
+    #[default]
+    Phrase,
+    /// Terms and `"quoted phrases"` combined with `AND`, `OR`, `NOT` and
+    /// parentheses, like FTS5 queries; adjacent terms are ANDed. Each term
+    /// is matched like [`SearchMode::Phrase`].
+    Boolean,
+    /// A Rust `regex` pattern, tested against every row.
+    Regex,
+    /// Shorthand for [`SearchMode::Phrase`] with `case_sensitive` set.
+    Literal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(default)]
+pub struct SearchOptions {
+    pub mode: SearchMode,
+    /// Otherwise ASCII case is ignored (any case, for regexes).
+    pub case_sensitive: bool,
+    /// Hits must not be directly preceded or followed by a word character.
+    pub whole_word: bool,
+}
+
+/// How the rows matching a search were found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchStrategy {
+    /// Every term was looked up in the trigram index.
+    Trigram,
+    /// Some terms are shorter than a trigram, or the query is a regex, so
+    /// rows were tested one by one.
+    Scan,
+}
+
+/// One occurrence of the search text inside a column value, as a half-open
+/// range `[start, end)` of char (not byte) offsets into that value, so the
+/// spans can be applied to the text without re-encoding it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MatchSpan {
+    pub column: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A compiled `fts_query`: the SQL condition selecting matching rows and the
+/// regex used to locate the hits inside them.
+pub(crate) struct Search {
+    pub condition: String,
+    pub strategy: SearchStrategy,
+    highlight: Option<Regex>,
+}
+
+impl Search {
+    pub(crate) fn new(text: &str, options: SearchOptions) -> Result<Self> {
+        let case_sensitive = options.case_sensitive || options.mode == SearchMode::Literal;
+        let matcher = Matcher {
+            case_sensitive,
+            whole_word: options.whole_word,
+        };
+        let search = match options.mode {
+            SearchMode::Phrase | SearchMode::Literal => Search {
+                condition: matcher.condition(text),
+                strategy: strategy(matcher.indexable(text)),
+                highlight: Some(matcher.regex(&[text.to_string()])?),
+            },
+            SearchMode::Boolean => {
+                let mut parser = BooleanParser {
+                    tokens: tokenize(text)?,
+                    pos: 0,
+                    matcher,
+                    terms: Vec::new(),
+                    indexed: true,
+                };
+                let condition = parser.parse_or(false)?;
+                if let Some(token) = parser.peek() {
+                    anyhow::bail!("Unexpected {} in search query", token);
+                }
+                let highlight = if parser.terms.is_empty() {
+                    None
+                } else {
+                    Some(matcher.regex(&parser.terms)?)
+                };
+                Search {
+                    condition,
+                    strategy: strategy(parser.indexed),
+                    highlight,
+                }
+            }
+            SearchMode::Regex => {
+                Regex::new(text).context("Invalid search regex")?;
+                let mut pattern = text.to_string();
+                if options.whole_word {
+                    pattern = format!(r"\b(?:{})\b", pattern);
+                }
+                if !case_sensitive {
+                    pattern = format!("(?i){}", pattern);
+                }
+                Search {
+                    condition: format!("raw REGEXP {}", quote(&pattern)),
+                    strategy: SearchStrategy::Scan,
+                    highlight: Some(Regex::new(&pattern)?),
+                }
+            }
+        };
+        Ok(search)
+    }
+
+    /// Spans of the hits in every cell of `row` (named by `headers`) except `id`.
+    pub(crate) fn row_matches(&self, headers: &[String], row: &[Option<String>]) -> Vec<MatchSpan> {
+        let mut spans = Vec::new();
+        let Some(regex) = &self.highlight else {
+            return spans;
+        };
+        for (column, value) in headers.iter().zip(row) {
+            let Some(value) = value.as_deref().filter(|_| column != "id") else {
+                continue;
+            };
+            let mut chars = 0;
+            let mut last = 0;
+            for m in regex.find_iter(value).filter(|m| !m.is_empty()) {
+                chars += value[last..m.start()].chars().count();
+                let len = m.as_str().chars().count();
+                spans.push(MatchSpan {
+                    column: column.clone(),
+                    start: chars,
+                    end: chars + len,
+                });
+                chars += len;
+                last = m.end();
+            }
+        }
+        spans
+    }
+}
+
+fn strategy(indexed: bool) -> SearchStrategy {
+    if indexed {
+        SearchStrategy::Trigram
+    } else {
+        SearchStrategy::Scan
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Rows whose `raw` contains `text`, ignoring ASCII case like SQLite's `LIKE`.
+///
+/// The trigram tokenizer with `detail='none'` rejects phrase queries longer
+/// than one trigram, but it can serve a plain two-argument `LIKE` from the
+/// index. Adding `ESCAPE` would lose the index, so `%` and `_` are left as
+/// wildcards in that prefilter and the exact substring is checked on top.
+fn like_condition(text: &str) -> String {
+    let mut cond = format!(
+        "id IN (SELECT rowid FROM logs_fts WHERE raw LIKE {})",
+        quote(&format!("%{}%", text))
+    );
+    if text.contains(['%', '_']) {
+        cond.push_str(&format!(
+            " AND instr(lower(raw), lower({})) > 0",
+            quote(text)
+        ));
+    }
+    cond
+}
+
+/// Rows whose `raw` contains `text`, tested on every row with `instr`.
+/// `lower` folds ASCII only, the same as `LIKE`.
+fn scan_condition(text: &str, case_sensitive: bool) -> String {
+    if case_sensitive {
+        format!("instr(raw, {}) > 0", quote(text))
+    } else {
+        format!("instr(lower(raw), lower({})) > 0", quote(text))
+    }
+}
+
+/// Rows whose `raw` contains `text` exactly. `GLOB` is case-sensitive and also
+/// served by the trigram index; its wildcards are escaped as bracket sets.
+fn glob_condition(text: &str) -> String {
+    let mut pattern = String::from("*");
+    for c in text.chars() {
+        match c {
+            '*' | '?' | '[' => pattern.push_str(&format!("[{}]", c)),
+            _ => pattern.push(c),
+        }
+    }
+    pattern.push('*');
+    format!(
+        "id IN (SELECT rowid FROM logs_fts WHERE raw GLOB {})",
+        quote(&pattern)
+    )
+}
+
+/// Matches plain text terms according to the case and whole word options.
+#[derive(Clone, Copy)]
+struct Matcher {
+    case_sensitive: bool,
+    whole_word: bool,
+}
+
+impl Matcher {
+    /// Rows containing `term`. The trigram index only answers substring
+    /// questions, so whole word matches are checked with a regex on top of
+    /// its candidates.
+    fn condition(&self, term: &str) -> String {
+        let mut cond = if !self.indexable(term) {
+            scan_condition(term, self.case_sensitive)
+        } else if self.case_sensitive {
+            glob_condition(term)
+        } else {
+            like_condition(term)
+        };
+        if self.whole_word {
+            cond.push_str(&format!(
+                " AND raw REGEXP {}",
+                quote(&self.pattern(&[term.to_string()]))
+            ));
+        }
+        cond
+    }
+
+    /// Whether the index can serve `term`: a `LIKE`/`GLOB` pattern without
+    /// three consecutive plain characters has no trigram to look up, and FTS5
+    /// would fall back to scanning the whole index.
+    fn indexable(&self, term: &str) -> bool {
+        let wildcards: &[char] = if self.case_sensitive {
+            &['*', '?', '[']
+        } else {
+            &['%', '_']
+        };
+        term.split(wildcards).any(|run| run.chars().count() >= 3)
+    }
+
+    fn pattern(&self, terms: &[String]) -> String {
+        let alternatives: Vec<String> = terms
+            .iter()
+            .map(|term| {
+                let mut alt = if self.case_sensitive {
+                    regex::escape(term)
+                } else {
+                    ascii_case_pattern(term)
+                };
+                // `\b` next to a non-word character would demand a word there.
+                if self.whole_word && term.starts_with(is_word_char) {
+                    alt.insert_str(0, r"\b");
+                }
+                if self.whole_word && term.ends_with(is_word_char) {
+                    alt.push_str(r"\b");
+                }
+                alt
+            })
+            .collect();
+        format!("(?:{})", alternatives.join("|"))
+    }
+
+    fn regex(&self, terms: &[String]) -> Result<Regex> {
+        Ok(Regex::new(&self.pattern(terms))?)
+    }
+}
+
+/// A regex matching `term` with ASCII letters in either case, the folding of
+/// SQLite's `lower` and `LIKE`. Unicode `(?i)` would also fold other letters
+/// and highlight hits the SQL condition never matched.
+fn ascii_case_pattern(term: &str) -> String {
+    term.chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() {
+                format!("[{}{}]", c.to_ascii_lowercase(), c.to_ascii_uppercase())
+            } else {
+                regex::escape(c.encode_utf8(&mut [0; 4]))
+            }
+        })
+        .collect()
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Term(String),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Term(term) => write!(f, "\"{}\"", term),
+            Token::And => f.write_str("AND"),
+            Token::Or => f.write_str("OR"),
+            Token::Not => f.write_str("NOT"),
+            Token::LParen => f.write_str("'('"),
+            Token::RParen => f.write_str("')'"),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::LParen);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::RParen);
+            }
+            '"' => {
+                chars.next();
+                let mut phrase = String::new();
+                loop {
+                    match chars.next() {
+                        // `""` inside a phrase is a literal quote, as in FTS5.
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            phrase.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => phrase.push(c),
+                        None => anyhow::bail!("Unterminated quote in search query"),
+                    }
+                }
+                tokens.push(Token::Term(phrase));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Term(word),
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent over a boolean query, producing SQL directly.
+/// Precedence from loosest: `OR`, `AND` (explicit or implied), `NOT`.
+struct BooleanParser {
+    tokens: Vec<Token>,
+    pos: usize,
+    matcher: Matcher,
+    /// Terms outside any `NOT`, i.e. the ones worth highlighting.
+    terms: Vec<String>,
+    /// Whether every term so far can use the trigram index.
+    indexed: bool,
+}
+
+impl BooleanParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn parse_or(&mut self, negated: bool) -> Result<String> {
+        let mut parts = vec![self.parse_and(negated)?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            parts.push(self.parse_and(negated)?);
+        }
+        Ok(join(parts, " OR "))
+    }
+
+    fn parse_and(&mut self, negated: bool) -> Result<String> {
+        let mut parts = vec![self.parse_not(negated)?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => self.pos += 1,
+                // `a NOT b` reads as `a AND NOT b`.
+                Some(Token::Term(_) | Token::Not | Token::LParen) => {}
+                _ => break,
+            }
+            parts.push(self.parse_not(negated)?);
+        }
+        Ok(join(parts, " AND "))
+    }
+
+    fn parse_not(&mut self, negated: bool) -> Result<String> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return Ok(format!("NOT {}", self.parse_not(!negated)?));
+        }
+        self.parse_primary(negated)
+    }
+
+    fn parse_primary(&mut self, negated: bool) -> Result<String> {
+        match self.peek().cloned() {
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let inner = self.parse_or(negated)?;
+                if self.peek() != Some(&Token::RParen) {
+                    anyhow::bail!("Missing ')' in search query");
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(Token::Term(term)) => {
+                self.pos += 1;
+                if !negated {
+                    self.terms.push(term.clone());
+                }
+                self.indexed &= self.matcher.indexable(&term);
+                Ok(format!("({})", self.matcher.condition(&term)))
+            }
+            Some(token) => anyhow::bail!("Unexpected {} in search query", token),
+            None => anyhow::bail!("Search query ends unexpectedly"),
+        }
+    }
+}
+
+fn join(mut parts: Vec<String>, sep: &str) -> String {
+    if parts.len() == 1 {
+        parts.remove(0)
+    } else {
+        format!("({})", parts.join(sep))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::LogQuery;
+    use crate::test_util;
+
+    const RULES: &str = r#"
+[logs]
+msg = "$line.msg"
+"#;
+
+    fn search(text: &str, mode: SearchMode) -> Search {
+        let options = SearchOptions {
+            mode,
+            ..Default::default()
+        };
+        Search::new(text, options).unwrap()
+    }
+
+    /// The `msg` of every row `fts_query` matches, and the highlighted text.
+    fn hits(lines: &[&str], text: &str, options: SearchOptions) -> (Vec<String>, Vec<String>) {
+        let engine = test_util::engine(RULES, lines);
+        let page = engine
+            .get_logs(&LogQuery {
+                fts_query: text.to_string(),
+                search_options: options,
+                limit: 100,
+                ..Default::default()
+            })
+            .unwrap();
+        let msg = page.result.headers.iter().position(|h| h == "msg").unwrap();
+        let rows: Vec<String> = page
+            .result
+            .rows
+            .iter()
+            .map(|r| r[msg].clone().unwrap_or_default())
+            .collect();
+        let highlighted = page
+            .matches
+            .iter()
+            .zip(&rows)
+            .flat_map(|(spans, value)| {
+                spans
+                    .iter()
+                    .filter(|s| s.column == "msg")
+                    .map(|s| value.chars().skip(s.start).take(s.end - s.start).collect())
+            })
+            .collect();
+        (rows, highlighted)
+    }
+
+    #[test]
+    fn terms_shorter_than_a_trigram_fall_back_to_a_scan() {
+        assert_eq!(
+            search("ab", SearchMode::Phrase).strategy,
+            SearchStrategy::Scan
+        );
+        assert_eq!(
+            search("abc", SearchMode::Phrase).strategy,
+            SearchStrategy::Trigram
+        );
+        // One short term is enough to scan every row.
+        assert_eq!(
+            search("error OR ab", SearchMode::Boolean).strategy,
+            SearchStrategy::Scan
+        );
+        // Wildcards do not count towards a trigram.
+        assert_eq!(
+            search("a%b", SearchMode::Phrase).strategy,
+            SearchStrategy::Scan
+        );
+    }
+
+    #[test]
+    fn short_terms_still_match() {
+        let (rows, highlighted) = hits(
+            &[r#"{"msg": "id ok"}"#, r#"{"msg": "nothing"}"#],
+            "OK",
+            SearchOptions::default(),
+        );
+        assert_eq!(rows, ["id ok"]);
+        assert_eq!(highlighted, ["ok"]);
+    }
+
+    #[test]
+    fn not_excludes_rows_and_is_not_highlighted() {
+        let options = SearchOptions {
+            mode: SearchMode::Boolean,
+            ..Default::default()
+        };
+        let (rows, highlighted) = hits(
+            &[
+                r#"{"msg": "error disk"}"#,
+                r#"{"msg": "error timeout"}"#,
+                r#"{"msg": "timeout"}"#,
+            ],
+            "error NOT timeout",
+            options,
+        );
+        assert_eq!(rows, ["error disk"]);
+        assert_eq!(highlighted, ["error"]);
+    }
+
+    #[test]
+    fn quoted_phrases_are_one_term() {
+        let options = SearchOptions {
+            mode: SearchMode::Boolean,
+            ..Default::default()
+        };
+        let (rows, _) = hits(
+            &[r#"{"msg": "disk full"}"#, r#"{"msg": "full disk"}"#],
+            "\"disk full\"",
+            options,
+        );
+        assert_eq!(rows, ["disk full"]);
+        assert_eq!(
+            tokenize(r#""say ""hi""""#).unwrap(),
+            [Token::Term(r#"say "hi""#.into())]
+        );
+    }
+
+    #[test]
+    fn boolean_precedence_and_errors() {
+        let parse = |text| search(text, SearchMode::Boolean).condition;
+        // `a NOT b` reads as `a AND NOT b`, and AND binds tighter than OR.
+        assert!(parse("abc NOT def").contains(" AND NOT "));
+        assert!(parse("abc OR def ghi").starts_with("(("));
+        for bad in ["\"open", "(abc", "abc)", "NOT", "abc OR"] {
+            let options = SearchOptions {
+                mode: SearchMode::Boolean,
+                ..Default::default()
+            };
+            assert!(Search::new(bad, options).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn case_folding_is_ascii_only_for_rows_and_highlights() {
+        let lines = [r#"{"msg": "CAFÉ ERROR"}"#, r#"{"msg": "café error"}"#];
+        let (rows, highlighted) = hits(&lines, "café", SearchOptions::default());
+        assert_eq!(rows, ["café error"]);
+        assert_eq!(highlighted, ["café"]);
+        // The first row matches through "error" only, so "CAFÉ" is no hit.
+        let options = SearchOptions {
+            mode: SearchMode::Boolean,
+            ..Default::default()
+        };
+        let (rows, highlighted) = hits(&lines, "café OR error", options);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(highlighted, ["ERROR", "café", "error"]);
+    }
+
+    #[test]
+    fn whole_word_needs_word_boundaries() {
+        let options = SearchOptions {
+            whole_word: true,
+            ..Default::default()
+        };
+        let (rows, _) = hits(
+            &[r#"{"msg": "errors"}"#, r#"{"msg": "an error!"}"#],
+            "error",
+            options,
+        );
+        assert_eq!(rows, ["an error!"]);
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{Config, Engine, LoadOptions};
+
+/// An engine with `rules` loaded from `lines`, one JSON log per line.
+pub(crate) fn engine(rules: &str, lines: &[&str]) -> Engine {
+    let mut engine = Engine::new(Config::parse(rules).expect("test rules are valid"))
+        .expect("test engine opens");
+    let text = lines.join("\n");
+    engine
+        .load_reader(text.as_bytes(), &LoadOptions::default())
+        .expect("test lines load");
+    engine
+}
@@ -0,0 +1,445 @@
+use crate::config::Config;
+use crate::db::Db;
+use crate::expr::Expr;
+use rusqlite::Connection;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::ops::Range;
+use toml::Spanned;
+
+/// Column names the `logs` table defines itself. `ts` only exists with a
+/// `[time]` section, `raw_offset` and `raw_len` only with offset storage, but
+/// they are always reserved so a rule file works with every load option.
+const RESERVED: &[&str] = &["id", "raw", "ts", "raw_offset", "raw_len"];
+
+/// A problem in a rule file, located at `span`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// Byte range in the rule file.
+    pub span: Range<usize>,
+    /// 1-based line and column (in chars) where `span` starts.
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// The parts of a rule file that are checked beyond what [`Config`]
+/// deserialization does, with their positions.
+#[derive(Deserialize)]
+struct RuleSpans {
+    #[serde(default)]
+    logs: BTreeMap<Spanned<String>, Spanned<String>>,
+    time: Option<TimeSpans>,
+    #[serde(default)]
+    view: Vec<ViewSpans>,
+    #[serde(default)]
+    col: Vec<ColumnSpans>,
+}
+
+#[derive(Deserialize)]
+struct TimeSpans {
+    field: Spanned<String>,
+}
+
+#[derive(Deserialize)]
+struct ViewSpans {
+    filter: Option<Spanned<String>>,
+    #[serde(default)]
+    sort: Vec<SortSpans>,
+    #[serde(default)]
+    columns: Vec<Spanned<String>>,
+}
+
+#[derive(Deserialize)]
+struct SortSpans {
+    column: Spanned<String>,
+}
+
+#[derive(Deserialize)]
+struct ColumnSpans {
+    expr: Option<Spanned<String>>,
+    #[serde(default)]
+    row: Vec<CellSpans>,
+}
+
+#[derive(Deserialize)]
+struct CellSpans {
+    expr: Spanned<String>,
+}
+
+impl Config {
+    /// Parses rules in the `lkl2.toml` format, failing with every problem
+    /// [`Config::validate`] finds.
+    pub fn parse(source: &str) -> anyhow::Result<Self> {
+        check(source).map_err(|diagnostics| {
+            let lines: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+            anyhow::anyhow!(lines.join("\n"))
+        })
+    }
+
+    /// Checks a rule file and returns its problems, ordered by position:
+    /// TOML syntax and unknown keys or styles, then unknown variables and
+    /// malformed paths in `[logs]`, column names that are reserved,
+    /// duplicated or not plain SQL identifiers, a `[time]` field missing
+    /// from `[logs]`, and cell expressions reading unknown fields.
+    pub fn validate(source: &str) -> Vec<Diagnostic> {
+        check(source).err().unwrap_or_default()
+    }
+}
+
+/// Problems found by the passes of [`check`], with the byte range each is
+/// about.
+type Problems = Vec<(Range<usize>, String)>;
+
+fn check(source: &str) -> Result<Config, Vec<Diagnostic>> {
+    let syntax_error = |e: toml::de::Error| {
+        let span = e.span().unwrap_or(0..0);
+        vec![diagnostic(source, span, e.message().to_string())]
+    };
+    let config: Config = toml::from_str(source).map_err(syntax_error)?;
+    let spans: RuleSpans = toml::from_str(source).map_err(syntax_error)?;
+
+    let mut problems = Problems::new();
+    check_logs(&spans.logs, &mut problems);
+    if let Some(time) = &spans.time {
+        check_time(time, &config, &mut problems);
+    }
+    check_exprs(source, &spans.col, &config, &mut problems);
+    check_views(&spans.view, &config, &mut problems);
+
+    if problems.is_empty() {
+        return Ok(config);
+    }
+    problems.sort_by_key(|(span, _)| span.start);
+    Err(problems
+        .into_iter()
+        .map(|(span, message)| diagnostic(source, span, message))
+        .collect())
+}
+
+/// The column names and paths of `[logs]`.
+fn check_logs(logs: &BTreeMap<Spanned<String>, Spanned<String>>, problems: &mut Problems) {
+    let sql = Connection::open_in_memory().ok();
+    // In file order, so a duplicate is reported where it repeats a name.
+    let mut logs: Vec<_> = logs.iter().collect();
+    logs.sort_by_key(|(name, _)| name.span().start);
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for (name, path) in logs {
+        if let Some(message) = check_name(name.get_ref(), sql.as_ref()) {
+            problems.push((name.span(), message));
+        } else if let Some(first) = seen.insert(name.get_ref().to_lowercase(), name.get_ref()) {
+            problems.push((
+                name.span(),
+                format!(
+                    "Column '{}' duplicates '{}', SQL column names ignore case",
+                    name.get_ref(),
+                    first
+                ),
+            ));
+        }
+        if let Some(message) = check_path(path.get_ref()) {
+            problems.push((path.span(), message));
+        }
+    }
+}
+
+/// The `[time]` field, which must be a column of `[logs]`.
+fn check_time(time: &TimeSpans, config: &Config, problems: &mut Problems) {
+    if !config.logs.contains_key(time.field.get_ref()) {
+        problems.push((
+            time.field.span(),
+            format!(
+                "Time field '{}' is not defined in [logs]",
+                time.field.get_ref()
+            ),
+        ));
+    }
+}
+
+/// The fields read by the cell expressions of `[[col]]`.
+fn check_exprs(source: &str, cols: &[ColumnSpans], config: &Config, problems: &mut Problems) {
+    let exprs = cols
+        .iter()
+        .flat_map(|col| col.expr.iter().chain(col.row.iter().map(|r| &r.expr)));
+    for expr in exprs {
+        // Syntax errors were reported when parsing the Config.
+        let Ok(parsed) = Expr::parse(expr.get_ref()) else {
+            continue;
+        };
+        for field in parsed.fields() {
+            if field == "id" || config.logs.contains_key(field) {
+                continue;
+            }
+            // Point at the reference itself when it is written out as is,
+            // otherwise (escaped) at the whole string.
+            let reference = format!("${}", field);
+            let span = match source[expr.span()].find(&reference) {
+                Some(i) => {
+                    let start = expr.span().start + i;
+                    start..start + reference.len()
+                }
+                None => expr.span(),
+            };
+            problems.push((
+                span,
+                format!(
+                    "Unknown field '{}' in expr, expected $id or a field of [logs]",
+                    reference
+                ),
+            ));
+        }
+    }
+}
+
+/// The sort keys, columns and filters of `[[view]]`, which would otherwise
+/// only fail once the view is used.
+fn check_views(views: &[ViewSpans], config: &Config, problems: &mut Problems) {
+    let is_column = |name: &str| name == "id" || config.logs.contains_key(name);
+    let is_sortable = |name: &str| is_column(name) || (name == "ts" && config.time.is_some());
+    // Filters are compiled against the table the rules define; if the rules
+    // cannot make one, that has been reported already.
+    let db = Db::new(config, None).ok();
+    for view in views {
+        for key in &view.sort {
+            let column = key.column.get_ref();
+            if !is_sortable(column) {
+                problems.push((
+                    key.column.span(),
+                    format!("Unknown sort column '{}' in view", column),
+                ));
+            }
+        }
+        for column in &view.columns {
+            if !is_column(column.get_ref()) {
+                problems.push((
+                    column.span(),
+                    format!("Unknown column '{}' in view", column.get_ref()),
+                ));
+            }
+        }
+        let (Some(filter), Some(db)) = (&view.filter, &db) else {
+            continue;
+        };
+        if filter.get_ref().trim().is_empty() {
+            continue;
+        }
+        let sql = format!("SELECT 1 FROM logs WHERE ({})", filter.get_ref());
+        let message = match db.conn.prepare(&sql) {
+            Ok(_) => continue,
+            // Without the wrapped statement, which is not what the user wrote.
+            Err(rusqlite::Error::SqlInputError { msg, .. }) => msg,
+            Err(e) => e.to_string(),
+        };
+        problems.push((
+            filter.span(),
+            format!("Invalid filter in view: {}", message),
+        ));
+    }
+}
+
+/// Why `name` cannot be a column of `logs`, if it cannot.
+pub(crate) fn check_name(name: &str, sql: Option<&Connection>) -> Option<String> {
+    let plain = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !plain {
+        return Some(format!(
+            "Column name '{}' must be letters, digits and _, not starting with a digit",
+            name
+        ));
+    }
+    if RESERVED.iter().any(|r| r.eq_ignore_ascii_case(name)) {
+        return Some(format!("Column name '{}' is reserved", name));
+    }
+    // Filters use column names unquoted, so let SQLite decide which
+    // keywords still work as names.
+    let sql = sql?;
+    if sql
+        .prepare(&format!("SELECT {name} FROM (SELECT 1 AS {name})"))
+        .is_err()
+    {
+        return Some(format!(
+            "Column name '{}' is an SQL keyword, choose another name",
+            name
+        ));
+    }
+    None
+}
+
+/// Why `path` is not a valid `[logs]` value, if it is not.
+fn check_path(path: &str) -> Option<String> {
+    if matches!(path, "$line" | "$0" | "$lineno") {
+        return None;
+    }
+    let Some(variable) = path.strip_prefix('$') else {
+        return Some(format!(
+            "'{}' is not a path, expected $line.<key>..., $line, $0 or $lineno",
+            path
+        ));
+    };
+    let (variable, keys) = variable.split_once('.').unwrap_or((variable, ""));
+    match variable {
+        "line" | "0" => {}
+        "lineno" => return Some("$lineno has no keys".to_string()),
+        _ => {
+            return Some(format!(
+                "Unknown variable '${}', expected $line, $0 or $lineno",
+                variable
+            ))
+        }
+    }
+    if keys.split('.').any(str::is_empty) {
+        return Some(format!("Empty key in path '{}'", path));
+    }
+    None
+}
+
+fn diagnostic(source: &str, span: Range<usize>, message: String) -> Diagnostic {
+    let start = span.start.min(source.len());
+    let before = &source[..start];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    Diagnostic {
+        message,
+        span,
+        line,
+        column,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Line, column and the text under the span of every diagnostic.
+    fn positions(source: &str) -> Vec<(usize, usize, &str)> {
+        Config::validate(source)
+            .into_iter()
+            .map(|d| (d.line, d.column, &source[d.span]))
+            .collect()
+    }
+
+    #[test]
+    fn valid_rules_have_no_diagnostics() {
+        let source = "[logs]\nlevel = \"$line.level\"\n\n[time]\nfield = \"level\"\n";
+        assert!(Config::validate(source).is_empty());
+        assert!(Config::parse(source).is_ok());
+    }
+
+    #[test]
+    fn names_and_paths_in_file_order() {
+        let source = "[logs]\nid = \"$line.x\"\nLevel = \"$line.level\"\nlevel = \"$lin.level\"\n";
+        assert_eq!(
+            positions(source),
+            [
+                (2, 1, "id"),
+                // The duplicate, not the first spelling.
+                (4, 1, "level"),
+                (4, 9, "\"$lin.level\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn columns_count_chars() {
+        let source = "[logs]\n\"größe\" = \"$line.a\"\n\"é\" = \"$line..b\"\n";
+        assert_eq!(
+            positions(source),
+            [
+                (2, 1, "\"größe\""),
+                (3, 1, "\"é\""),
+                // After `"é" = `, which is six chars but seven bytes.
+                (3, 7, "\"$line..b\""),
+            ]
+        );
+        let last = Config::validate(source).pop().unwrap();
+        assert_eq!(last.to_string(), "3:7: Empty key in path '$line..b'");
+    }
+
+    #[test]
+    fn time_field_and_expr_fields() {
+        let source = "[logs]\nlevel = \"$line.level\"\n\n[time]\nfield = \"ts\"\n\n\
+                      [[col]]\nexpr = \"concat($nope, $level, $id)\"\n\n\
+                      [[col]]\n[[col.row]]\nexpr = \"hex($other)\"\n";
+        assert_eq!(
+            positions(source),
+            [(5, 9, "\"ts\""), (8, 16, "$nope"), (12, 13, "$other"),]
+        );
+    }
+
+    #[test]
+    fn escaped_reference_reports_the_whole_expr() {
+        let source = "[logs]\na = \"$line.a\"\n\n[[col]]\nexpr = \"concat($a, $\\u0062)\"\n";
+        assert_eq!(positions(source), [(5, 8, "\"concat($a, $\\u0062)\"")]);
+    }
+
+    #[test]
+    fn syntax_error_is_the_only_diagnostic() {
+        let source = "[logs]\nid = \"$line.x\"\nlevel = \n";
+        let diagnostics = Config::validate(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn view_sorts_columns_and_filters() {
+        let source = r#"[logs]
+level = "$line.level"
+
+[[view]]
+name = "typos"
+filter = "levle = 'x'"
+sort = [{ column = "ts" }, { column = "lvl" }]
+columns = ["id", "raw"]
+
+[[view]]
+name = "broken"
+filter = "level = 'x' AND"
+
+[[view]]
+name = "fine"
+filter = "level REGEXP 'x' AND id > 1"
+sort = [{ column = "level", descending = true }, { column = "id" }]
+columns = ["level", "id"]
+"#;
+        assert_eq!(
+            positions(source),
+            [
+                (6, 10, "\"levle = 'x'\""),
+                (7, 20, "\"ts\""),
+                (7, 39, "\"lvl\""),
+                (8, 18, "\"raw\""),
+                (12, 10, "\"level = 'x' AND\""),
+            ]
+        );
+        let messages: Vec<String> = Config::validate(source)
+            .into_iter()
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "Invalid filter in view: no such column: levle",
+                "Unknown sort column 'ts' in view",
+                "Unknown sort column 'lvl' in view",
+                "Unknown column 'raw' in view",
+                "Invalid filter in view: near \")\": syntax error",
+            ]
+        );
+    }
+
+    #[test]
+    fn views_may_sort_by_time() {
+        let source = "[logs]\nt = \"$line.t\"\n\n[time]\nfield = \"t\"\n\n\
+                      [[view]]\nname = \"recent\"\nfilter = \"ts > 0\"\n\
+                      sort = [{ column = \"ts\", descending = true }]\n";
+        assert!(Config::validate(source).is_empty());
+    }
+}